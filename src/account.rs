@@ -0,0 +1,104 @@
+use serde::Deserialize;
+use wasm_bindgen::JsValue;
+
+use crate::chains::ChainConfig;
+use crate::watchlist::WatchedAddress;
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Coin {
+    pub denom: String,
+    pub amount: String,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Delegation {
+    pub validator_address: String,
+    pub balance: Coin,
+}
+
+// Bank balances, delegations and pending staking rewards for one address
+#[derive(Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct AccountSummary {
+    pub balances: Vec<Coin>,
+    pub delegations: Vec<Delegation>,
+    pub rewards: Vec<Coin>,
+}
+
+// Where the app's account comes from: nowhere yet, the wallet (its primary chain address) or a
+// watched address
+#[derive(Debug, Clone, PartialEq)]
+pub enum Connection {
+    Disconnected,
+    Wallet(String),
+    Watching(WatchedAddress),
+}
+
+// The address the Wallet and Vote views currently query: the connected wallet's, or a watched one
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveAccount {
+    pub address: String,
+    pub chain: ChainConfig,
+    pub watch_only: bool,
+}
+
+impl AccountSummary {
+    // Total bonded amount in the chain's base denom
+    pub fn total_staked(&self, chain: &ChainConfig) -> f64 {
+        self.delegations
+            .iter()
+            .filter(|d| d.balance.denom == chain.denom)
+            .filter_map(|d| d.balance.amount.parse::<f64>().ok())
+            .sum()
+    }
+}
+
+// Render a base-denom amount in display units, e.g. 1500000 uscrt -> "1.5000 SCRT"
+pub fn format_amount(amount: f64, denom: &str, chain: &ChainConfig) -> String {
+    if denom == chain.denom {
+        format!("{:.4} {}", amount / 10f64.powi(chain.decimals as i32), chain.symbol)
+    } else {
+        format!("{} {}", amount, denom)
+    }
+}
+
+// Parse an amount typed in display units into base units, e.g. "1.5" SCRT -> 1500000 uscrt
+pub fn parse_amount(input: &str, chain: &ChainConfig) -> Result<u128, String> {
+    let input = input.trim();
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    let decimals = chain.decimals as usize;
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("\"{}\" is not a valid amount", input));
+    }
+    if fraction.len() > decimals {
+        return Err(format!("{} supports at most {} decimals", chain.symbol, decimals));
+    }
+    let scale = 10u128.pow(decimals as u32);
+    let fraction_scale = 10u128.pow((decimals - fraction.len()) as u32);
+    let whole: u128 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| "Amount is too large".to_string())? };
+    let fraction: u128 = if fraction.is_empty() { 0 } else { fraction.parse().map_err(|_| "Invalid amount".to_string())? };
+    let amount = whole
+        .checked_mul(scale)
+        .and_then(|w| w.checked_add(fraction * fraction_scale))
+        .ok_or_else(|| "Amount is too large".to_string())?;
+    if amount == 0 {
+        return Err("Amount must be greater than zero".to_string());
+    }
+    Ok(amount)
+}
+
+pub fn format_coin(coin: &Coin, chain: &ChainConfig) -> String {
+    match coin.amount.parse::<f64>() {
+        Ok(amount) => format_amount(amount, &coin.denom, chain),
+        Err(_) => format!("{} {}", coin.amount, coin.denom),
+    }
+}
+
+pub async fn fetch_account_summary(chain: &ChainConfig, address: &str) -> Result<AccountSummary, String> {
+    crate::call_js_async(
+        "fetchAccountSummary",
+        &[JsValue::from_str(&chain.lcd), JsValue::from_str(address)],
+    )
+    .await
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+
+// Bech32 (BIP-173) address decoding, as used by every Cosmos SDK chain
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bech32Error {
+    MixedCase,
+    MissingSeparator,
+    InvalidLength,
+    InvalidChar(char),
+    InvalidChecksum,
+    InvalidPadding,
+    InvalidPrefix,
+}
+
+impl fmt::Display for Bech32Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Bech32Error::MixedCase => write!(f, "Address mixes upper and lower case"),
+            Bech32Error::MissingSeparator => write!(f, "Address has no '1' separator"),
+            Bech32Error::InvalidLength => write!(f, "Address has an invalid length"),
+            Bech32Error::InvalidChar(c) => write!(f, "Invalid character '{}'", c),
+            Bech32Error::InvalidChecksum => write!(f, "Checksum does not match"),
+            Bech32Error::InvalidPadding => write!(f, "Address data has invalid padding"),
+            Bech32Error::InvalidPrefix => write!(f, "Prefix must be lowercase letters and digits"),
+        }
+    }
+}
+
+// Decoded address: human-readable prefix plus the raw address bytes
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bech32Address {
+    pub prefix: String,
+    pub bytes: Vec<u8>,
+}
+
+fn polymod(values: &[u8]) -> u32 {
+    values.iter().fold(1u32, |chk, &value| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x1ffffff) << 5) ^ value as u32;
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(chk, |chk, (_, g)| chk ^ g)
+    })
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let bytes = hrp.as_bytes();
+    let mut expanded: Vec<u8> = bytes.iter().map(|b| b >> 5).collect();
+    expanded.push(0);
+    expanded.extend(bytes.iter().map(|b| b & 31));
+    expanded
+}
+
+// Regroup a bit stream from `from`-bit to `to`-bit words
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Result<Vec<u8>, Bech32Error> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let max = (1u32 << to) - 1;
+    let mut out = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+
+    for &value in data {
+        acc = (acc << from) | value as u32;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            out.push(((acc >> bits) & max) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            out.push(((acc << (to - bits)) & max) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max) != 0 {
+        return Err(Bech32Error::InvalidPadding);
+    }
+    Ok(out)
+}
+
+pub fn decode(address: &str) -> Result<Bech32Address, Bech32Error> {
+    let has_lower = address.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = address.chars().any(|c| c.is_ascii_uppercase());
+    if has_lower && has_upper {
+        return Err(Bech32Error::MixedCase);
+    }
+    let address = address.to_ascii_lowercase();
+    if address.len() < 8 || address.len() > 90 {
+        return Err(Bech32Error::InvalidLength);
+    }
+
+    let separator = address.rfind('1').ok_or(Bech32Error::MissingSeparator)?;
+    let (prefix, data) = (&address[..separator], &address[separator + 1..]);
+    if prefix.is_empty() || data.len() < 6 {
+        return Err(Bech32Error::InvalidLength);
+    }
+    if let Some(c) = prefix.chars().find(|c| !(33..=126).contains(&(*c as u32))) {
+        return Err(Bech32Error::InvalidChar(c));
+    }
+
+    let values = data
+        .chars()
+        .map(|c| {
+            CHARSET
+                .iter()
+                .position(|&x| x as char == c)
+                .map(|p| p as u8)
+                .ok_or(Bech32Error::InvalidChar(c))
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+
+    let mut checked = hrp_expand(prefix);
+    checked.extend(&values);
+    if polymod(&checked) != 1 {
+        return Err(Bech32Error::InvalidChecksum);
+    }
+
+    let payload = &values[..values.len() - 6];
+    Ok(Bech32Address {
+        prefix: prefix.to_string(),
+        bytes: convert_bits(payload, 5, 8, false)?,
+    })
+}
+
+fn create_checksum(prefix: &str, values: &[u8]) -> Vec<u8> {
+    let mut checked = hrp_expand(prefix);
+    checked.extend(values);
+    checked.extend([0u8; 6]);
+    let modulus = polymod(&checked) ^ 1;
+    (0..6).map(|i| ((modulus >> (5 * (5 - i))) & 31) as u8).collect()
+}
+
+pub fn encode(prefix: &str, bytes: &[u8]) -> Result<String, Bech32Error> {
+    if prefix.is_empty() || prefix.chars().any(|c| !c.is_ascii_lowercase() && !c.is_ascii_digit()) {
+        return Err(Bech32Error::InvalidPrefix);
+    }
+    let values = convert_bits(bytes, 8, 5, true)?;
+    let checksum = create_checksum(prefix, &values);
+    let data: String = values
+        .iter()
+        .chain(checksum.iter())
+        .map(|&v| CHARSET[v as usize] as char)
+        .collect();
+    Ok(format!("{}1{}", prefix, data))
+}
+
+// Re-encode an address under another prefix; the underlying bytes stay the same
+pub fn convert(address: &str, prefix: &str) -> Result<String, Bech32Error> {
+    let decoded = decode(address)?;
+    encode(prefix, &decoded.bytes)
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
@@ -0,0 +1,417 @@
+use gloo_utils::format::JsValueSerdeExt;
+use leptos::*;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use wasm_bindgen::JsValue;
+
+use crate::account;
+use crate::bech32;
+use crate::chains::ChainConfig;
+use crate::governance::{self, DepositParams, TxResult, TxState};
+use crate::params::{self, ParamsDiff};
+
+// Drafting a gov v1 proposal in the app instead of hand-written CLI JSON: the same proposal can be
+// exported for `tx gov submit-proposal` (e.g. with --generate-only for a multisig) or signed here
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalKind {
+    Text,
+    CommunityPoolSpend,
+    ParamChange,
+}
+
+impl ProposalKind {
+    pub const ALL: [ProposalKind; 3] = [ProposalKind::Text, ProposalKind::CommunityPoolSpend, ProposalKind::ParamChange];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            ProposalKind::Text => "text",
+            ProposalKind::CommunityPoolSpend => "community-pool-spend",
+            ProposalKind::ParamChange => "param-change",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProposalKind::Text => "Text",
+            ProposalKind::CommunityPoolSpend => "Community pool spend",
+            ProposalKind::ParamChange => "Parameter change",
+        }
+    }
+
+    // Whether window.submitProposal can encode the messages; MsgUpdateParams differs per module,
+    // so parameter changes are export only
+    pub fn can_sign(&self) -> bool {
+        !matches!(self, ProposalKind::ParamChange)
+    }
+}
+
+// The builder form as typed; amounts are in display units
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProposalDraft {
+    pub kind: ProposalKind,
+    pub title: String,
+    pub summary: String,
+    pub metadata: String,
+    pub deposit: String,
+    pub recipient: String,
+    pub amount: String,
+    // e.g. "/cosmos.staking.v1beta1.MsgUpdateParams"
+    pub params_type_url: String,
+    // The module's complete params object, as JSON
+    pub params: String,
+}
+
+impl Default for ProposalDraft {
+    fn default() -> Self {
+        ProposalDraft {
+            kind: ProposalKind::Text,
+            title: String::new(),
+            summary: String::new(),
+            metadata: String::new(),
+            deposit: String::new(),
+            recipient: String::new(),
+            amount: String::new(),
+            params_type_url: "/cosmos.staking.v1beta1.MsgUpdateParams".to_string(),
+            params: String::new(),
+        }
+    }
+}
+
+// A validated draft, ready to export or sign
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuiltProposal {
+    pub kind: ProposalKind,
+    pub messages: Vec<Value>,
+    // Initial deposit in base units of the chain's denom
+    pub deposit: u128,
+    pub title: String,
+    pub summary: String,
+    pub metadata: String,
+}
+
+impl BuiltProposal {
+    fn initial_deposit(&self, chain: &ChainConfig) -> Vec<Value> {
+        if self.deposit == 0 {
+            return Vec::new();
+        }
+        vec![json!({ "denom": chain.denom, "amount": self.deposit.to_string() })]
+    }
+
+    // The MsgSubmitProposal the wallet signs
+    pub fn to_msg(&self, chain: &ChainConfig, proposer: &str) -> Value {
+        json!({
+            "@type": "/cosmos.gov.v1.MsgSubmitProposal",
+            "messages": self.messages,
+            "initial_deposit": self.initial_deposit(chain),
+            "proposer": proposer,
+            "metadata": self.metadata,
+            "title": self.title,
+            "summary": self.summary,
+            "expedited": false,
+        })
+    }
+
+    // proposal.json as read by `<daemon> tx gov submit-proposal`
+    pub fn to_cli_json(&self, chain: &ChainConfig) -> Value {
+        let deposit = if self.deposit == 0 { String::new() } else { format!("{}{}", self.deposit, chain.denom) };
+        json!({
+            "messages": self.messages,
+            "metadata": self.metadata,
+            "deposit": deposit,
+            "title": self.title,
+            "summary": self.summary,
+            "expedited": false,
+        })
+    }
+}
+
+// `authority` is the gov module account, which every proposal message must be sent from
+pub fn build(draft: &ProposalDraft, chain: &ChainConfig, authority: Option<&str>) -> Result<BuiltProposal, String> {
+    let title = draft.title.trim();
+    let summary = draft.summary.trim();
+    if title.is_empty() {
+        return Err("A proposal needs a title".to_string());
+    }
+    if summary.is_empty() {
+        return Err("A proposal needs a summary".to_string());
+    }
+    let deposit = if draft.deposit.trim().is_empty() { 0 } else { account::parse_amount(&draft.deposit, chain)? };
+    let authority = || authority.ok_or_else(|| "The governance module address hasn't loaded yet".to_string());
+
+    let messages = match draft.kind {
+        ProposalKind::Text => Vec::new(),
+        ProposalKind::CommunityPoolSpend => {
+            let recipient = draft.recipient.trim();
+            let decoded = bech32::decode(recipient).map_err(|e| format!("Invalid recipient: {}", e))?;
+            if decoded.prefix != chain.bech32_prefix {
+                return Err(format!("The recipient must be a {} address", chain.bech32_prefix));
+            }
+            let amount = account::parse_amount(&draft.amount, chain)?;
+            vec![json!({
+                "@type": "/cosmos.distribution.v1beta1.MsgCommunityPoolSpend",
+                "authority": authority()?,
+                "recipient": recipient,
+                "amount": [{ "denom": chain.denom, "amount": amount.to_string() }],
+            })]
+        }
+        ProposalKind::ParamChange => {
+            let type_url = draft.params_type_url.trim();
+            if params::params_path(type_url).is_none() {
+                return Err(format!("{} is not a MsgUpdateParams type", type_url));
+            }
+            let params: Value = serde_json::from_str(&draft.params).map_err(|e| format!("Parameters aren't valid JSON: {}", e))?;
+            if !params.is_object() {
+                return Err("Parameters must be a JSON object with every field of the module's params".to_string());
+            }
+            vec![json!({ "@type": type_url, "authority": authority()?, "params": params })]
+        }
+    };
+
+    Ok(BuiltProposal {
+        kind: draft.kind,
+        messages,
+        deposit,
+        title: title.to_string(),
+        summary: summary.to_string(),
+        metadata: draft.metadata.trim().to_string(),
+    })
+}
+
+#[derive(Deserialize)]
+struct ModuleAccount {
+    address: String,
+}
+
+pub async fn fetch_gov_authority(chain: &ChainConfig) -> Result<String, String> {
+    let account: ModuleAccount =
+        crate::call_js_async("fetchModuleAccount", &[JsValue::from_str(&chain.lcd), JsValue::from_str("gov")]).await?;
+    Ok(account.address)
+}
+
+pub async fn submit_proposal(chain: &ChainConfig, proposal: &BuiltProposal) -> Result<TxResult, String> {
+    let request = json!({
+        "messages": proposal.messages,
+        "initial_deposit": proposal.initial_deposit(chain),
+        "metadata": proposal.metadata,
+        "title": proposal.title,
+        "summary": proposal.summary,
+    });
+    let request = JsValue::from_serde(&request).map_err(|e| format!("Failed to serialize proposal: {:?}", e))?;
+    crate::call_js_async(
+        "submitProposal",
+        &[request, JsValue::from_str(&chain.chain_id), JsValue::from_str(&chain.lcd), JsValue::from_str(chain.fee_denom())],
+    )
+    .await
+}
+
+// data: URL for an <a download> link to the CLI proposal file
+fn json_data_url(value: &Value) -> String {
+    let json = serde_json::to_string_pretty(value).unwrap_or_default();
+    format!("data:application/json;charset=utf-8,{}", js_sys::encode_uri_component(&json))
+}
+
+// Proposal builder for one chain; `proposer` is the address shown in the preview and
+// `sign_unavailable` says why the connected wallet can't submit it, if it can't
+#[component]
+pub fn ProposalBuilder(
+    cx: Scope,
+    chain: ChainConfig,
+    proposer: Signal<Option<String>>,
+    sign_unavailable: Signal<Option<&'static str>>,
+) -> impl IntoView {
+    let draft = create_rw_signal(cx, ProposalDraft::default());
+    // Only a change of type re-renders the type-specific fields, so typing in them keeps focus
+    let kind = create_memo(cx, move |_| draft.get().kind);
+    let (authority, set_authority) = create_signal(cx, None::<Result<String, String>>);
+    let (deposit_params, set_deposit_params) = create_signal(cx, None::<DepositParams>);
+    let (preview, set_preview) = create_signal(cx, None::<Result<BuiltProposal, String>>);
+    let (params_state, set_params_state) = create_signal(cx, None::<String>);
+    let (tx_state, set_tx_state) = create_signal(cx, None::<TxState>);
+
+    let load_chain = chain.clone();
+    spawn_local(async move {
+        let gov = fetch_gov_authority(&load_chain).await;
+        if let Err(err) = &gov {
+            log::error!("❌ Error fetching the gov module address of {}: {}", load_chain.chain_id, err);
+        }
+        set_authority.set(Some(gov));
+        match governance::fetch_deposit_params(&load_chain).await {
+            Ok(params) => set_deposit_params.set(Some(params)),
+            Err(err) => log::error!("❌ Error fetching deposit params of {}: {}", load_chain.chain_id, err),
+        }
+    });
+
+    // Any edit invalidates the preview, so only what was reviewed can be exported or signed
+    let edit = move |change: &dyn Fn(&mut ProposalDraft)| {
+        draft.update(|d| change(d));
+        set_preview.set(None);
+        set_tx_state.set(None);
+    };
+
+    let params_chain = chain.clone();
+    let load_current_params = move |_| {
+        let type_url = draft.get_untracked().params_type_url.trim().to_string();
+        let Some(path) = params::params_path(&type_url) else {
+            set_params_state.set(Some(format!("{} is not a MsgUpdateParams type", type_url)));
+            return;
+        };
+        set_params_state.set(Some("Loading current parameters...".to_string()));
+        let lcd = params_chain.lcd.clone();
+        spawn_local(async move {
+            match params::fetch_current_params(&lcd, &path).await {
+                Ok(current) => {
+                    let pretty = serde_json::to_string_pretty(&current).unwrap_or_default();
+                    edit(&|d| d.params = pretty.clone());
+                    set_params_state.set(None);
+                }
+                Err(err) => {
+                    log::error!("❌ Error fetching {} params: {}", path, err);
+                    set_params_state.set(Some(format!("Could not load the current parameters: {}", err)));
+                }
+            }
+        });
+    };
+
+    let build_chain = chain.clone();
+    let build_preview = move |_| {
+        let gov = authority.get_untracked().and_then(|a| a.ok());
+        set_preview.set(Some(build(&draft.get_untracked(), &build_chain, gov.as_deref())));
+    };
+
+    let submit_chain = chain.clone();
+    let sign = move |_| {
+        let Some(Ok(proposal)) = preview.get_untracked() else {
+            return;
+        };
+        let chain = submit_chain.clone();
+        log::info!("📝 Submitting {} proposal \"{}\" on {}", proposal.kind.label(), proposal.title, chain.chain_id);
+        set_tx_state.set(Some(TxState::Pending));
+        spawn_local(async move {
+            let state = match submit_proposal(&chain, &proposal).await {
+                Ok(tx) => TxState::Done(tx),
+                Err(err) => {
+                    log::error!("❌ Submitting the proposal failed: {}", err);
+                    TxState::Failed(err)
+                }
+            };
+            set_tx_state.set(Some(state));
+        });
+    };
+
+    let min_deposit_chain = chain.clone();
+    let diff_lcd = chain.lcd.clone();
+    let tx_chain = chain.clone();
+    view! { cx,
+        <div class="proposal-builder">
+            {move || authority.get().and_then(|a| a.err()).map(|err| view! { cx,
+                <p class="watch-error">{format!("Could not load the governance module address ({}); only text proposals can be built.", err)}</p>
+            })}
+            <div class="builder-field">
+                <label>"Type"</label>
+                <select class="chain-select" on:change=move |ev| {
+                    let key = crate::event_select_value(&ev);
+                    let kind = ProposalKind::ALL.into_iter().find(|k| k.key() == key).unwrap_or(ProposalKind::Text);
+                    edit(&|d| d.kind = kind);
+                }>
+                    {ProposalKind::ALL.iter().map(|k| view! { cx, <option value={k.key()}>{k.label()}</option> }).collect::<Vec<_>>()}
+                </select>
+            </div>
+            <div class="builder-field">
+                <label>"Title"</label>
+                <input class="watch-input" prop:value=move || draft.get().title
+                    on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.title = v.clone()); } />
+            </div>
+            <div class="builder-field">
+                <label>"Summary (Markdown)"</label>
+                <textarea class="builder-textarea" rows="8" prop:value=move || draft.get().summary
+                    on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.summary = v.clone()); }></textarea>
+            </div>
+            <div class="builder-field">
+                <label>"Metadata (optional, e.g. an ipfs:// link to the full document)"</label>
+                <input class="watch-input" prop:value=move || draft.get().metadata
+                    on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.metadata = v.clone()); } />
+            </div>
+            {move || match kind.get() {
+                ProposalKind::Text => view! { cx, <span></span> }.into_view(cx),
+                ProposalKind::CommunityPoolSpend => view! { cx,
+                    <div>
+                        <div class="builder-field">
+                            <label>"Recipient"</label>
+                            <input class="watch-input" prop:value=move || draft.get().recipient
+                                on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.recipient = v.clone()); } />
+                        </div>
+                        <div class="builder-field">
+                            <label>"Amount"</label>
+                            <input class="watch-input" type="number" min="0" prop:value=move || draft.get().amount
+                                on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.amount = v.clone()); } />
+                        </div>
+                    </div>
+                }.into_view(cx),
+                ProposalKind::ParamChange => view! { cx,
+                    <div>
+                        <div class="builder-field">
+                            <label>"Message type"</label>
+                            <input class="watch-input" prop:value=move || draft.get().params_type_url
+                                on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.params_type_url = v.clone()); } />
+                            <button class="vote-button" on:click=load_current_params.clone()>"Load current parameters"</button>
+                        </div>
+                        {move || params_state.get().map(|state| view! { cx, <p>{state}</p> })}
+                        <div class="builder-field">
+                            <label>"Parameters (the complete params object; fields left out are reset)"</label>
+                            <textarea class="builder-textarea" rows="12" prop:value=move || draft.get().params
+                                on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.params = v.clone()); }></textarea>
+                        </div>
+                    </div>
+                }.into_view(cx),
+            }}
+            <div class="builder-field">
+                <label>"Initial deposit"</label>
+                <input class="watch-input" type="number" min="0" prop:value=move || draft.get().deposit
+                    on:input=move |ev| { let v = event_target_value(&ev); edit(&|d| d.deposit = v.clone()); } />
+                {move || deposit_params.get().map(|params| {
+                    let minimum = governance::coin_total(&params.min_deposit, &min_deposit_chain.denom);
+                    view! { cx,
+                        <span>{format!("Minimum to enter voting: {}", account::format_amount(minimum, &min_deposit_chain.denom, &min_deposit_chain))}</span>
+                    }
+                })}
+            </div>
+            <button class="vote-button" on:click=build_preview>"Preview"</button>
+            {move || match preview.get() {
+                None => view! { cx, <span></span> }.into_view(cx),
+                Some(Err(err)) => view! { cx, <p class="watch-error">{err}</p> }.into_view(cx),
+                Some(Ok(proposal)) => {
+                    let msg = proposal.to_msg(&chain, proposer.get().as_deref().unwrap_or("<your address>"));
+                    let cli = proposal.to_cli_json(&chain);
+                    let params_diff = proposal.messages.iter().find(|m| params::params_path(m["@type"].as_str().unwrap_or_default()).is_some()).map(|m| {
+                        let type_url = m["@type"].as_str().unwrap_or_default().to_string();
+                        view! { cx, <ParamsDiff lcd=diff_lcd.clone() type_url=type_url proposed=m["params"].clone() /> }
+                    });
+                    let can_sign = proposal.kind.can_sign();
+                    view! { cx,
+                        <div class="builder-preview">
+                            <h3>"MsgSubmitProposal"</h3>
+                            {params_diff}
+                            <pre class="formatted-json">{serde_json::to_string_pretty(&msg).unwrap_or_default()}</pre>
+                            <a class="vote-button" href={json_data_url(&cli)} download="proposal.json">"⬇️ Export proposal.json"</a>
+                            <p class="simulator-note">"Submit with `<daemon> tx gov submit-proposal proposal.json --from <key>`; add --generate-only to get an unsigned transaction for a multisig."</p>
+                            {match (can_sign, sign_unavailable.get()) {
+                                (false, _) => view! { cx,
+                                    <p class="simulator-note">"Parameter changes can't be signed in the browser yet; export the JSON and submit it with the CLI."</p>
+                                }.into_view(cx),
+                                (true, Some(reason)) => view! { cx,
+                                    <p class="simulator-note">{reason}" You can still export the JSON and submit it with the CLI."</p>
+                                }.into_view(cx),
+                                (true, None) => view! { cx,
+                                    <button class="vote-button" on:click=sign.clone()
+                                        disabled=move || matches!(tx_state.get(), Some(TxState::Pending))>"✍️ Sign and broadcast"</button>
+                                }.into_view(cx),
+                            }}
+                        </div>
+                    }.into_view(cx)
+                }
+            }}
+            {move || tx_state.get().map(|state| crate::render_tx_state(cx, &state, &tx_chain))}
+        </div>
+    }
+}
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::bech32;
+use crate::storage;
+
+// Chain the wallet connects to first; its address is the SCRT address shown on the Wallet view
+pub const PRIMARY_CHAIN_ID: &str = "secret-4";
+
+// Chains derived on connect when the user hasn't picked their own list yet
+const DEFAULT_CHAIN_IDS: [&str; 4] = ["cosmoshub-4", "celestia", "osmosis-1", "noble-1"];
+
+// localStorage key holding the chain ids the user has configured
+const CHAINS_STORAGE_KEY: &str = "yolonode.chains";
+
+// Bundled snapshot of the chain-registry entries we know how to talk to
+const CHAIN_REGISTRY_JSON: &str = include_str!("chain-registry.json");
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ChainConfig {
+    pub chain_id: String,
+    pub chain_name: String,
+    pub symbol: String,
+    pub bech32_prefix: String,
+    pub slip44: u32,
+    pub denom: String,
+    pub decimals: u32,
+    pub lcd: String,
+    pub icon: Option<String>,
+    // Block explorer base URL; transactions live under {explorer}/tx/{hash}
+    #[serde(default)]
+    pub explorer: Option<String>,
+    // Denom transaction fees are paid in, when it isn't the staking denom (e.g. uusdc on Noble)
+    #[serde(default)]
+    pub fee_denom: Option<String>,
+    // False for chains without an x/gov module (e.g. Noble), which the Vote view leaves out
+    #[serde(default = "default_governance")]
+    pub governance: bool,
+}
+
+fn default_governance() -> bool {
+    true
+}
+
+impl ChainConfig {
+
+    pub fn fee_denom(&self) -> &str {
+        self.fee_denom.as_deref().unwrap_or(&self.denom)
+    }
+
+    pub fn tx_url(&self, tx_hash: &str) -> Option<String> {
+        self.explorer.as_ref().map(|explorer| format!("{}/tx/{}", explorer, tx_hash))
+    }
+}
+
+// Per-chain wallet connection state
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectionStatus {
+    NotConnected,
+    Connected(String),
+    // Watch-only address on a chain whose coin type differs, so its address can't be derived
+    CoinTypeMismatch,
+    Error,
+}
+
+impl fmt::Display for ConnectionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionStatus::NotConnected => write!(f, "Not Connected"),
+            ConnectionStatus::Connected(address) => write!(f, "{}", address),
+            ConnectionStatus::CoinTypeMismatch => write!(f, "Different coin type"),
+            ConnectionStatus::Error => write!(f, "Error fetching address"),
+        }
+    }
+}
+
+// All chains in the bundled registry snapshot, parsed on first use
+pub fn chain_registry() -> &'static [ChainConfig] {
+    static REGISTRY: OnceLock<Vec<ChainConfig>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        serde_json::from_str(CHAIN_REGISTRY_JSON).unwrap_or_else(|e| {
+            log::error!("❌ Failed to parse bundled chain registry: {:?}", e);
+            Vec::new()
+        })
+    })
+}
+
+// The primary chain, even if the bundled registry failed to parse or lost its entry
+pub fn primary_chain() -> ChainConfig {
+    find_chain(PRIMARY_CHAIN_ID).unwrap_or_else(|| {
+        log::error!("❌ {} missing from the bundled chain registry, using built-in defaults", PRIMARY_CHAIN_ID);
+        ChainConfig {
+            chain_id: PRIMARY_CHAIN_ID.to_string(),
+            chain_name: "Secret Network".to_string(),
+            symbol: "SCRT".to_string(),
+            bech32_prefix: "secret".to_string(),
+            slip44: 529,
+            denom: "uscrt".to_string(),
+            decimals: 6,
+            lcd: "https://rpc.ankr.com/http/scrt_cosmos".to_string(),
+            icon: Some("/static/icons/Scrt.svg".to_string()),
+            explorer: Some("https://www.mintscan.io/secret".to_string()),
+            fee_denom: None,
+            governance: true,
+        }
+    })
+}
+
+pub fn find_chain(chain_id: &str) -> Option<ChainConfig> {
+    chain_registry().iter().find(|chain| chain.chain_id == chain_id).cloned()
+}
+
+pub fn find_chain_by_prefix(prefix: &str) -> Option<ChainConfig> {
+    chain_registry().iter().find(|chain| chain.bech32_prefix == prefix).cloned()
+}
+
+// Registry chains that can still be added to the configured list
+pub fn available_chains(configured: &[ChainConfig]) -> Vec<ChainConfig> {
+    chain_registry()
+        .iter()
+        .filter(|chain| chain.chain_id != PRIMARY_CHAIN_ID)
+        .filter(|chain| !configured.iter().any(|c| c.chain_id == chain.chain_id))
+        .cloned()
+        .collect()
+}
+
+// Placeholder status for every configured chain
+pub fn disconnected_addresses(chains: &[ChainConfig]) -> Vec<(ChainConfig, ConnectionStatus)> {
+    chains
+        .iter()
+        .map(|chain| (chain.clone(), ConnectionStatus::NotConnected))
+        .collect()
+}
+
+// Load the configured chain list, falling back to the defaults
+pub fn load_chain_configs() -> Vec<ChainConfig> {
+    let ids = storage::load::<Vec<String>>(CHAINS_STORAGE_KEY)
+        .unwrap_or_else(|| DEFAULT_CHAIN_IDS.iter().map(|id| id.to_string()).collect());
+    ids.iter().filter_map(|id| find_chain(id)).collect()
+}
+
+pub fn save_chain_configs(chains: &[ChainConfig]) {
+    let ids: Vec<&str> = chains.iter().map(|chain| chain.chain_id.as_str()).collect();
+    storage::save(CHAINS_STORAGE_KEY, &ids);
+}
+
+// Derive a watched address on other chains by swapping the bech32 prefix. This only holds
+// for chains sharing the source chain's coin type, since the key derivation path differs otherwise.
+pub fn derive_watch_addresses(
+    address: &str,
+    source: &ChainConfig,
+    chains: &[ChainConfig],
+) -> Vec<(ChainConfig, ConnectionStatus)> {
+    chains
+        .iter()
+        .map(|chain| {
+            let status = if chain.slip44 != source.slip44 {
+                ConnectionStatus::CoinTypeMismatch
+            } else {
+                bech32::convert(address, &chain.bech32_prefix)
+                    .map(ConnectionStatus::Connected)
+                    .unwrap_or(ConnectionStatus::Error)
+            };
+            (chain.clone(), status)
+        })
+        .collect()
+}
+
+// Every account and validator-operator prefix in the registry, for the address converter
+pub fn known_prefixes() -> Vec<String> {
+    chain_registry()
+        .iter()
+        .flat_map(|chain| [chain.bech32_prefix.clone(), format!("{}valoper", chain.bech32_prefix)])
+        .collect()
+}
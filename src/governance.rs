@@ -0,0 +1,538 @@
+use std::collections::HashMap;
+
+use gloo_utils::format::JsValueSerdeExt;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::JsValue;
+
+use crate::account::Coin;
+use crate::bech32;
+use crate::chains::ChainConfig;
+
+// YoloNode's own validator on the primary chain, the reference for vote agreement stats
+pub const YOLONODE_VALIDATOR: &str = "secretvaloper1pkzmfk34qg46p4hen0dnlkn05rzje65xk4tzjc";
+
+// Vote weights are 18-decimal fixed point (cosmos.Dec); a percentage point is 10^16 of them
+const WEIGHT_ONE: u128 = 1_000_000_000_000_000_000;
+const PERCENT_DECIMALS: usize = 16;
+
+// Gov vote options, in the order the Vote view shows them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoteOption {
+    Yes,
+    No,
+    Abstain,
+    NoWithVeto,
+}
+
+impl VoteOption {
+    pub const ALL: [VoteOption; 4] = [VoteOption::Yes, VoteOption::No, VoteOption::Abstain, VoteOption::NoWithVeto];
+
+    // Name understood by window.voteOnProposal
+    pub fn key(&self) -> &'static str {
+        match self {
+            VoteOption::Yes => "Yes",
+            VoteOption::No => "No",
+            VoteOption::Abstain => "Abstain",
+            VoteOption::NoWithVeto => "NoWithVeto",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            VoteOption::NoWithVeto => "No With Veto",
+            _ => self.key(),
+        }
+    }
+
+    // Parse the proto enum name used by the gov query endpoints, e.g. "VOTE_OPTION_YES"
+    pub fn from_proto(value: &str) -> Option<VoteOption> {
+        match value {
+            "VOTE_OPTION_YES" => Some(VoteOption::Yes),
+            "VOTE_OPTION_NO" => Some(VoteOption::No),
+            "VOTE_OPTION_ABSTAIN" => Some(VoteOption::Abstain),
+            "VOTE_OPTION_NO_WITH_VETO" => Some(VoteOption::NoWithVeto),
+            _ => None,
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            VoteOption::Yes => "yes",
+            VoteOption::No => "no",
+            VoteOption::Abstain => "abstain",
+            VoteOption::NoWithVeto => "veto",
+        }
+    }
+}
+
+// One leg of a split vote, serialized for window.voteWeightedOnProposal
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct WeightedVoteOption {
+    pub option: &'static str,
+    pub weight: String,
+}
+
+// Parse a percentage such as "70" or "33.33" into 18-decimal weight units
+fn parse_percent(input: &str) -> Result<u128, String> {
+    let input = input.trim().trim_end_matches('%');
+    if input.is_empty() {
+        return Ok(0);
+    }
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    let invalid = || format!("\"{}\" is not a valid percentage", input);
+    if fraction.len() > PERCENT_DECIMALS
+        || !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+        || (whole.is_empty() && fraction.is_empty())
+    {
+        return Err(invalid());
+    }
+    let whole: u128 = if whole.is_empty() { 0 } else { whole.parse().map_err(|_| invalid())? };
+    let fraction: u128 = format!("{:0<width$}", fraction, width = PERCENT_DECIMALS).parse().map_err(|_| invalid())?;
+    whole
+        .checked_mul(10u128.pow(PERCENT_DECIMALS as u32))
+        .and_then(|w| w.checked_add(fraction))
+        .ok_or_else(|| format!("\"{}\" is too large", input))
+}
+
+// Parse a cosmos.Dec string such as "0.700000000000000000" into 18-decimal weight units
+fn parse_dec(input: &str) -> Option<u128> {
+    let input = input.trim();
+    let (whole, fraction) = input.split_once('.').unwrap_or((input, ""));
+    if fraction.len() > 18 {
+        return None;
+    }
+    let whole: u128 = whole.parse().ok()?;
+    let scale = 10u128.pow((18 - fraction.len()) as u32);
+    let fraction: u128 = if fraction.is_empty() { 0 } else { fraction.parse().ok()? };
+    whole.checked_mul(WEIGHT_ONE)?.checked_add(fraction * scale)
+}
+
+fn format_weight(weight: u128) -> String {
+    format!("{}.{:018}", weight / WEIGHT_ONE, weight % WEIGHT_ONE)
+}
+
+// Validate the percentages entered per option and build MsgVoteWeighted options.
+// Weights must sum to exactly 100%; options left empty or at 0 are dropped.
+pub fn build_weighted_options(inputs: &[(VoteOption, String)]) -> Result<Vec<WeightedVoteOption>, String> {
+    let mut weights = Vec::new();
+    for (option, input) in inputs {
+        let weight = parse_percent(input).map_err(|e| format!("{}: {}", option.label(), e))?;
+        if weight > 0 {
+            weights.push((*option, weight));
+        }
+    }
+
+    let total = weights
+        .iter()
+        .try_fold(0u128, |total, (_, w)| total.checked_add(*w))
+        .ok_or("Weights add up to more than 100%, they must add up to 100%")?;
+    if total != WEIGHT_ONE {
+        return Err(format!("Weights add up to {}%, they must add up to 100%", describe_percent(total)));
+    }
+
+    Ok(weights
+        .into_iter()
+        .map(|(option, weight)| WeightedVoteOption { option: option.key(), weight: format_weight(weight) })
+        .collect())
+}
+
+// Weight as a percentage without trailing zeros, e.g. "33.33"; split at the percent point so
+// no weight can overflow
+fn describe_percent(weight: u128) -> String {
+    let unit = 10u128.pow(PERCENT_DECIMALS as u32);
+    let percent = format!("{}.{:0width$}", weight / unit, weight % unit, width = PERCENT_DECIMALS);
+    percent.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+// Human-readable split, e.g. "70% Yes / 30% Abstain"
+pub fn describe_split(options: &[WeightedVoteOption]) -> String {
+    options
+        .iter()
+        .map(|o| {
+            let label = VoteOption::ALL.iter().find(|v| v.key() == o.option).map(|v| v.label()).unwrap_or(o.option);
+            let weight = o.weight.replace('.', "").parse::<u128>().unwrap_or(0);
+            format!("{}% {}", describe_percent(weight), label)
+        })
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct VoteWeight {
+    pub option: String,
+    pub weight: String,
+}
+
+// A recorded vote from /cosmos/gov/v1/proposals/{id}/votes/{voter}
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Vote {
+    pub voter: String,
+    #[serde(default)]
+    pub options: Vec<VoteWeight>,
+}
+
+impl Vote {
+    // The single option voted, or None for a split vote
+    pub fn single_option(&self) -> Option<VoteOption> {
+        match self.options.as_slice() {
+            [only] => VoteOption::from_proto(&only.option),
+            _ => None,
+        }
+    }
+
+    // The option carrying most of the weight, which is the whole vote unless it was split
+    pub fn main_option(&self) -> Option<VoteOption> {
+        self.options
+            .iter()
+            .filter_map(|o| Some((VoteOption::from_proto(&o.option)?, parse_dec(&o.weight)?)))
+            .max_by_key(|(_, weight)| *weight)
+            .map(|(option, _)| option)
+    }
+
+    // "Yes", or "70% Yes / 30% Abstain" for split votes
+    pub fn describe(&self) -> String {
+        if let Some(option) = self.single_option() {
+            return option.label().to_string();
+        }
+        let options: Vec<WeightedVoteOption> = self
+            .options
+            .iter()
+            .filter_map(|o| {
+                let option = VoteOption::from_proto(&o.option)?;
+                Some(WeightedVoteOption { option: option.key(), weight: format_weight(parse_dec(&o.weight)?) })
+            })
+            .collect();
+        describe_split(&options)
+    }
+}
+
+// A vote found in the voter's transaction history
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+struct HistoricalVote {
+    proposal_id: String,
+    #[serde(flatten)]
+    vote: Vote,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+struct VoteScan {
+    votes: Vec<HistoricalVote>,
+    truncated: bool,
+    max_txs: usize,
+}
+
+// Votes found in an address' transaction history
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VoteHistory {
+    pub votes: HashMap<u64, Vote>,
+    // Set when the scan stopped at this many transactions, so older votes may be missing
+    pub truncated_at: Option<usize>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Validator {
+    pub operator_address: String,
+    pub moniker: String,
+    pub tokens: String,
+}
+
+// How the active account stands on one proposal: its own vote, or if it hasn't voted,
+// the votes of the validators it delegates to (which it inherits)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccountVoteStatus {
+    pub own_vote: ValidatorVote,
+    pub validator_votes: Vec<(String, ValidatorVote)>,
+}
+
+impl AccountVoteStatus {
+    // Only a lookup that found no vote counts; a failed one may hide a vote
+    pub fn awaits_vote(&self) -> bool {
+        self.own_vote == ValidatorVote::NotVoted
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct DepositParams {
+    pub min_deposit: Vec<Coin>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Deposit {
+    pub depositor: String,
+    pub amount: Vec<Coin>,
+}
+
+// Sum of `coins` in one denom, in base units
+pub fn coin_total(coins: &[Coin], denom: &str) -> f64 {
+    coins
+        .iter()
+        .filter(|c| c.denom == denom)
+        .filter_map(|c| c.amount.parse::<f64>().ok())
+        .sum()
+}
+
+// Broadcast outcome as returned by wallet.js' txResult()
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TxResult {
+    pub tx_hash: String,
+    pub code: u32,
+    pub raw_log: String,
+}
+
+impl TxResult {
+    pub fn succeeded(&self) -> bool {
+        self.code == 0
+    }
+}
+
+// Progress of a transaction started from the Vote view
+#[derive(Debug, Clone, PartialEq)]
+pub enum TxState {
+    Pending,
+    Done(TxResult),
+    Failed(String),
+}
+
+pub async fn vote_on_proposal(chain: &ChainConfig, proposal_id: u64, option: VoteOption) -> Result<TxResult, String> {
+    crate::call_js_async(
+        "voteOnProposal",
+        &[
+            JsValue::from_str(&proposal_id.to_string()),
+            JsValue::from_str(option.key()),
+            JsValue::from_str(&chain.chain_id),
+            JsValue::from_str(&chain.lcd),
+            JsValue::from_str(chain.fee_denom()),
+        ],
+    )
+    .await
+}
+
+pub async fn vote_weighted_on_proposal(
+    chain: &ChainConfig,
+    proposal_id: u64,
+    options: &[WeightedVoteOption],
+) -> Result<TxResult, String> {
+    let options = JsValue::from_serde(options).map_err(|e| format!("Failed to serialize vote options: {:?}", e))?;
+    crate::call_js_async(
+        "voteWeightedOnProposal",
+        &[
+            JsValue::from_str(&proposal_id.to_string()),
+            options,
+            JsValue::from_str(&chain.chain_id),
+            JsValue::from_str(&chain.lcd),
+            JsValue::from_str(chain.fee_denom()),
+        ],
+    )
+    .await
+}
+
+// Every proposal `voter` has voted on, keyed by proposal id. Unlike fetch_votes_of this also covers
+// proposals whose votes were pruned after tallying.
+pub async fn fetch_vote_history(chain: &ChainConfig, voter: &str) -> Result<VoteHistory, String> {
+    let scan: VoteScan =
+        crate::call_js_async("fetchVoteHistory", &[JsValue::from_str(&chain.lcd), JsValue::from_str(voter)]).await?;
+    Ok(VoteHistory {
+        votes: scan
+            .votes
+            .into_iter()
+            .filter_map(|v| Some((v.proposal_id.parse().ok()?, v.vote)))
+            .collect(),
+        truncated_at: scan.truncated.then_some(scan.max_txs),
+    })
+}
+
+// The vote history of a validator, which votes from its operator account
+pub async fn fetch_validator_vote_history(chain: &ChainConfig, valoper: &str) -> Result<VoteHistory, String> {
+    let account = bech32::convert(valoper, &chain.bech32_prefix).map_err(|e| e.to_string())?;
+    fetch_vote_history(chain, &account).await
+}
+
+pub async fn fetch_validators(lcd: &str) -> Result<Vec<Validator>, String> {
+    crate::call_js_async("fetchValidators", &[JsValue::from_str(lcd)]).await
+}
+
+// Where a validator (or any voter) stands on a proposal; Unknown when its vote couldn't be
+// looked up, which must not be mistaken for not having voted
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidatorVote {
+    Voted(Vote),
+    NotVoted,
+    Unknown(String),
+}
+
+impl ValidatorVote {
+    pub fn vote(&self) -> Option<&Vote> {
+        match self {
+            ValidatorVote::Voted(vote) => Some(vote),
+            _ => None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            ValidatorVote::Voted(vote) => vote.describe(),
+            ValidatorVote::NotVoted => "Did not vote".to_string(),
+            ValidatorVote::Unknown(_) => "Unknown".to_string(),
+        }
+    }
+}
+
+// One entry of window.fetchVotesOf's answer
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+struct VoteLookup {
+    #[serde(default)]
+    vote: Option<Vote>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+// How each voter voted on a proposal, in the order given; an empty address comes back Unknown
+async fn fetch_votes_of(chain: &ChainConfig, proposal_id: u64, voters: &[String]) -> Result<Vec<ValidatorVote>, String> {
+    let voters = JsValue::from_serde(voters).map_err(|e| format!("Failed to serialize voters: {:?}", e))?;
+    let lookups: Vec<VoteLookup> = crate::call_js_async(
+        "fetchVotesOf",
+        &[JsValue::from_str(&chain.lcd), JsValue::from_str(&proposal_id.to_string()), voters],
+    )
+    .await?;
+    Ok(lookups
+        .into_iter()
+        .map(|lookup| match (lookup.error, lookup.vote) {
+            (Some(err), _) => ValidatorVote::Unknown(err),
+            (None, Some(vote)) => ValidatorVote::Voted(vote),
+            (None, None) => ValidatorVote::NotVoted,
+        })
+        .collect())
+}
+
+// Validators vote from their operator account, i.e. the valoper bytes under the account prefix;
+// an address that doesn't convert is left empty so its lookup reports Unknown
+fn operator_accounts<'a>(chain: &ChainConfig, valopers: impl Iterator<Item = &'a str>) -> Vec<String> {
+    valopers
+        .map(|valoper| {
+            bech32::convert(valoper, &chain.bech32_prefix).unwrap_or_else(|err| {
+                log::warn!("❌ Invalid validator address {}: {}", valoper, err);
+                String::new()
+            })
+        })
+        .collect()
+}
+
+// How each validator voted on a proposal, in the order given
+pub async fn fetch_validator_votes(chain: &ChainConfig, proposal_id: u64, validators: &[Validator]) -> Result<Vec<ValidatorVote>, String> {
+    let voters = operator_accounts(chain, validators.iter().map(|v| v.operator_address.as_str()));
+    fetch_votes_of(chain, proposal_id, &voters).await
+}
+
+// The account's own vote, falling back to its validators' votes when it hasn't voted
+pub async fn fetch_account_vote_status(
+    chain: &ChainConfig,
+    proposal_id: u64,
+    voter: &str,
+    validators: &[String],
+) -> Result<AccountVoteStatus, String> {
+    let own_vote = fetch_votes_of(chain, proposal_id, &[voter.to_string()])
+        .await?
+        .pop()
+        .unwrap_or_else(|| ValidatorVote::Unknown("no answer".to_string()));
+    let validator_votes = if own_vote == ValidatorVote::NotVoted {
+        let voters = operator_accounts(chain, validators.iter().map(String::as_str));
+        validators.iter().cloned().zip(fetch_votes_of(chain, proposal_id, &voters).await?).collect()
+    } else {
+        Vec::new()
+    };
+    Ok(AccountVoteStatus { own_vote, validator_votes })
+}
+
+pub async fn fetch_deposit_params(chain: &ChainConfig) -> Result<DepositParams, String> {
+    crate::call_js_async("fetchDepositParams", &[JsValue::from_str(&chain.lcd)]).await
+}
+
+pub async fn fetch_deposits(chain: &ChainConfig, proposal_id: u64) -> Result<Vec<Deposit>, String> {
+    crate::call_js_async(
+        "fetchProposalDeposits",
+        &[JsValue::from_str(&chain.lcd), JsValue::from_str(&proposal_id.to_string())],
+    )
+    .await
+}
+
+pub async fn deposit_on_proposal(chain: &ChainConfig, proposal_id: u64, amount: u128) -> Result<TxResult, String> {
+    crate::call_js_async(
+        "depositOnProposal",
+        &[
+            JsValue::from_str(&proposal_id.to_string()),
+            JsValue::from_str(&amount.to_string()),
+            JsValue::from_str(&chain.denom),
+            JsValue::from_str(&chain.chain_id),
+            JsValue::from_str(&chain.lcd),
+            JsValue::from_str(chain.fee_denom()),
+        ],
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs(values: &[(VoteOption, &str)]) -> Vec<(VoteOption, String)> {
+        values.iter().map(|(option, value)| (*option, value.to_string())).collect()
+    }
+
+    #[test]
+    fn builds_a_70_30_split() {
+        let options =
+            build_weighted_options(&inputs(&[(VoteOption::Yes, "70"), (VoteOption::Abstain, "30%"), (VoteOption::No, "")]))
+                .unwrap();
+        assert_eq!(
+            options,
+            vec![
+                WeightedVoteOption { option: "Yes", weight: "0.700000000000000000".to_string() },
+                WeightedVoteOption { option: "Abstain", weight: "0.300000000000000000".to_string() },
+            ]
+        );
+        assert_eq!(describe_split(&options), "70% Yes / 30% Abstain");
+    }
+
+    #[test]
+    fn keeps_fractional_percentages_exact() {
+        let options = build_weighted_options(&inputs(&[
+            (VoteOption::Yes, "33.33"),
+            (VoteOption::No, "33.33"),
+            (VoteOption::Abstain, "33.34"),
+        ]))
+        .unwrap();
+        assert_eq!(options[0].weight, "0.333300000000000000");
+        assert_eq!(options[2].weight, "0.333400000000000000");
+        assert_eq!(describe_split(&options), "33.33% Yes / 33.33% No / 33.34% Abstain");
+    }
+
+    #[test]
+    fn rejects_sums_other_than_100() {
+        let under = build_weighted_options(&inputs(&[(VoteOption::Yes, "60"), (VoteOption::No, "30")]));
+        assert_eq!(under, Err("Weights add up to 90%, they must add up to 100%".to_string()));
+        let over = build_weighted_options(&inputs(&[(VoteOption::Yes, "60"), (VoteOption::No, "40.5")]));
+        assert_eq!(over, Err("Weights add up to 100.5%, they must add up to 100%".to_string()));
+        assert!(build_weighted_options(&inputs(&[(VoteOption::Yes, ""), (VoteOption::No, "0")])).is_err());
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        for input in ["abc", "1,5", "-10", ".", "1.2.3", "50.00000000000000001"] {
+            assert!(parse_percent(input).is_err(), "{} should be rejected", input);
+        }
+        let err = build_weighted_options(&inputs(&[(VoteOption::Yes, "ten")])).unwrap_err();
+        assert!(err.starts_with("Yes: "), "{}", err);
+    }
+
+    #[test]
+    fn rejects_overflowing_input_instead_of_wrapping() {
+        assert!(parse_percent(&"9".repeat(24)).is_err());
+        assert!(parse_percent(&"9".repeat(50)).is_err());
+        let huge = "34028236692093846346337";
+        assert!(parse_percent(huge).is_ok());
+        let err = build_weighted_options(&inputs(&[(VoteOption::Yes, huge), (VoteOption::No, huge)])).unwrap_err();
+        assert_eq!(err, "Weights add up to more than 100%, they must add up to 100%");
+        assert_eq!(parse_dec(&"9".repeat(30)), None);
+        assert_eq!(describe_percent(u128::MAX), "34028236692093846346337.4607431768211455");
+    }
+}
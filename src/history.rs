@@ -0,0 +1,77 @@
+use crate::governance::{Vote, VoteOption};
+use crate::status::ProposalStatus;
+
+// Governance participation of one address across the chain's proposal history
+
+// One proposal that reached its voting period, seen from the address
+pub struct HistoryEntry<'a> {
+    pub status: &'a ProposalStatus,
+    pub vote: Option<&'a Vote>,
+    // The validator the address is compared against, if its votes are known
+    pub reference_vote: Option<&'a Vote>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryStats {
+    // Proposals whose voting period has ended
+    pub finished: usize,
+    pub voted: usize,
+    // Non-abstain votes on finished proposals, and how many matched the outcome
+    pub decisive_votes: usize,
+    pub agreed_with_outcome: usize,
+    // Proposals both the address and the reference validator voted on, and how many votes matched
+    pub shared_votes: usize,
+    pub agreed_with_reference: usize,
+}
+
+fn ratio(part: usize, whole: usize) -> Option<f64> {
+    (whole > 0).then(|| part as f64 / whole as f64)
+}
+
+impl HistoryStats {
+    pub fn participation(&self) -> Option<f64> {
+        ratio(self.voted, self.finished)
+    }
+
+    pub fn outcome_agreement(&self) -> Option<f64> {
+        ratio(self.agreed_with_outcome, self.decisive_votes)
+    }
+
+    pub fn reference_agreement(&self) -> Option<f64> {
+        ratio(self.agreed_with_reference, self.shared_votes)
+    }
+}
+
+// Whether a vote went the way the proposal ended; abstaining agrees with neither outcome
+pub fn agrees_with_outcome(vote: &Vote, passed: bool) -> Option<bool> {
+    match vote.main_option()? {
+        VoteOption::Abstain => None,
+        VoteOption::Yes => Some(passed),
+        VoteOption::No | VoteOption::NoWithVeto => Some(!passed),
+    }
+}
+
+pub fn stats(entries: &[HistoryEntry]) -> HistoryStats {
+    let mut stats = HistoryStats::default();
+    for entry in entries {
+        let outcome = entry.status.passed();
+        if outcome.is_some() {
+            stats.finished += 1;
+        }
+        let Some(vote) = entry.vote else {
+            continue;
+        };
+        if let Some(passed) = outcome {
+            stats.voted += 1;
+            if let Some(agrees) = agrees_with_outcome(vote, passed) {
+                stats.decisive_votes += 1;
+                stats.agreed_with_outcome += usize::from(agrees);
+            }
+        }
+        if let Some(reference) = entry.reference_vote {
+            stats.shared_votes += 1;
+            stats.agreed_with_reference += usize::from(vote.main_option() == reference.main_option());
+        }
+    }
+    stats
+}
@@ -0,0 +1,101 @@
+// iCalendar (RFC 5545) export of proposal voting deadlines
+
+use crate::time::civil_from_days;
+
+const PRODUCT_ID: &str = "-//YoloNode//Governance Deadlines//EN";
+
+// How long before the deadline (the event's DTEND) the reminder fires
+const REMINDER: &str = "-PT24H";
+
+// Length of the calendar event, ending at the voting deadline
+const EVENT_MINUTES: f64 = 30.0;
+
+pub struct DeadlineEvent {
+    pub proposal_id: u64,
+    pub chain_id: String,
+    pub title: String,
+    pub url: String,
+    // Voting end as milliseconds since the Unix epoch
+    pub end_ms: f64,
+}
+
+// UTC date-time in iCalendar form, e.g. 20240501T120000Z
+pub fn format_utc(ms: f64) -> String {
+    let seconds = (ms / 1000.0).floor() as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let secs_of_day = seconds.rem_euclid(86_400);
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60,
+        secs_of_day % 60
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Content lines are folded at 75 octets, continuation lines start with a space
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded
+}
+
+fn event_lines(event: &DeadlineEvent, stamp: &str) -> Vec<String> {
+    let summary = format!("Vote ends: #{} {}", event.proposal_id, event.title);
+    vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:proposal-{}@{}.yolonode.com", event.proposal_id, event.chain_id),
+        format!("DTSTAMP:{}", stamp),
+        format!("DTSTART:{}", format_utc(event.end_ms - EVENT_MINUTES * 60_000.0)),
+        format!("DTEND:{}", format_utc(event.end_ms)),
+        format!("SUMMARY:{}", escape(&summary)),
+        format!("DESCRIPTION:{}", escape(&format!("Voting on proposal #{} closes at this time.\n{}", event.proposal_id, event.url))),
+        format!("URL:{}", event.url),
+        "BEGIN:VALARM".to_string(),
+        "ACTION:DISPLAY".to_string(),
+        format!("DESCRIPTION:{}", escape(&format!("Voting on proposal #{} ends in 24 hours", event.proposal_id))),
+        format!("TRIGGER;RELATED=END:{}", REMINDER),
+        "END:VALARM".to_string(),
+        "END:VEVENT".to_string(),
+    ]
+}
+
+// A complete VCALENDAR with one event per deadline
+pub fn calendar(events: &[DeadlineEvent], now_ms: f64) -> String {
+    let stamp = format_utc(now_ms);
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODUCT_ID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+    ];
+    for event in events {
+        lines.extend(event_lines(event, &stamp));
+    }
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold(line)).collect::<Vec<_>>().join("\r\n") + "\r\n"
+}
+
+// data: URL for an <a download> link, so no server round trip is needed
+pub fn data_url(ics: &str) -> String {
+    format!("data:text/calendar;charset=utf-8,{}", js_sys::encode_uri_component(ics))
+}
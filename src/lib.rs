@@ -4,7 +4,6 @@ use wasm_bindgen_futures::spawn_local;
 use js_sys::{Promise, Reflect, Function};
 use wasm_bindgen::JsValue;
 use wasm_bindgen::JsCast;
-use log;
 use std::collections::HashMap;
 use gloo_utils::format::JsValueSerdeExt;
 use serde::Deserialize;
@@ -12,9 +11,48 @@ use serde::de::{self, Deserializer};
 use wasm_bindgen::closure::Closure;
 use web_sys::window;
 
+mod account;
+mod bech32;
+mod builder;
+mod chains;
+mod governance;
+mod history;
+mod ical;
+mod markdown;
+mod metadata;
+mod messages;
+mod params;
+mod seen;
+mod simulator;
+mod status;
+mod storage;
+mod tally;
+mod time;
+mod validator_votes;
+mod wallet;
+mod watchlist;
+
+use account::{AccountSummary, ActiveAccount, Connection};
+use builder::ProposalBuilder;
+use chains::{ChainConfig, ConnectionStatus};
+use governance::{
+    AccountVoteStatus, Deposit, DepositParams, TxState, ValidatorVote, Vote, VoteHistory, VoteOption, WeightedVoteOption,
+};
+use markdown::Markdown;
+use metadata::{MetadataSource, ProposalMetadata};
+use messages::{BlockTiming, DecodeContext, MessageCard};
+use seen::ProposalChanges;
+use simulator::WhatIfSimulator;
+use status::ProposalStatus;
+use tally::{TallyContext, TallyProgress, TallyResult};
+use time::Timestamp;
+use validator_votes::ValidatorVotes;
+use wallet::{WalletKind, WalletProvider};
+use watchlist::WatchedAddress;
+
 // Define structures to match the expected response formats
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 struct GovernanceProposal {
     #[serde(rename = "proposal_id", deserialize_with = "deserialize_string_to_u64")]
     id: Option<u64>, 
@@ -22,26 +60,34 @@ struct GovernanceProposal {
     content: Option<ProposalContent>,
     #[serde(rename = "messages")]
     messages: Option<Vec<serde_json::Value>>,
-    status: String,
-    #[serde(rename = "expiration_time")]
-    expiration_time: Option<String>, 
+    // Gov v1 only; filled in from legacy content by enrich_proposals
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    summary: String,
+    status: ProposalStatus,
+    final_tally_result: Option<TallyResult>,
+    #[serde(default, deserialize_with = "time::deserialize_optional")]
+    submit_time: Option<Timestamp>,
+    total_deposit: Option<Vec<account::Coin>>,
+    #[serde(default, deserialize_with = "time::deserialize_optional")]
+    deposit_end_time: Option<Timestamp>,
+    #[serde(default, deserialize_with = "time::deserialize_optional")]
+    voting_start_time: Option<Timestamp>,
+    #[serde(default, deserialize_with = "time::deserialize_optional")]
+    voting_end_time: Option<Timestamp>,
+    proposer: Option<String>,
+    metadata: Option<String>,
+    expedited: Option<bool>,
+    failed_reason: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, PartialEq)]
 struct ProposalContent {
     title: Option<String>,
     description: Option<String>,
 }
 
-#[derive(Deserialize, Debug, Clone)]
-struct ProposalPlan {}
-
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = window, catch)]
-    async fn getAddressForMultiChain(chain_id: &str) -> Result<JsValue, JsValue>;
-}
-
 // Fetch API data
 #[wasm_bindgen]
 extern "C" {
@@ -62,7 +108,8 @@ where
 {
     let value: serde_json::Value = Deserialize::deserialize(deserializer)?;
     match value {
-        serde_json::Value::String(s) => s.parse::<u64>().map(Some).map_err(de::Error::custom),
+        // Unparsable ids (e.g. "Unknown") leave the proposal without an id instead of failing the whole list
+        serde_json::Value::String(s) => Ok(s.parse::<u64>().ok()),
         serde_json::Value::Number(num) => num.as_u64().map(Some).ok_or_else(|| de::Error::custom("Invalid u64 number")),
         serde_json::Value::Null => Ok(None),
         _ => Err(de::Error::custom("Invalid type for u64 field")),
@@ -85,6 +132,21 @@ fn call_js_function(function_name: &str) -> Result<Function, JsValue> {
     func.dyn_into::<Function>()
 }
 
+// Call an async `window` function with arguments and deserialize what its Promise resolves to
+async fn call_js_async<T: serde::de::DeserializeOwned>(function_name: &str, args: &[JsValue]) -> Result<T, String> {
+    let js_func = call_js_function(function_name).map_err(|_| format!("{} not defined", function_name))?;
+    let promise = js_func
+        .apply(&JsValue::null(), &args.iter().collect::<js_sys::Array>())
+        .and_then(|val| val.dyn_into::<Promise>())
+        .map_err(|_| format!("{} did not return a Promise", function_name))?;
+    let result = wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map_err(|err| format!("{} failed: {:?}", function_name, err.as_string().unwrap_or_default()))?;
+    result
+        .into_serde::<T>()
+        .map_err(|e| format!("Failed to deserialize {} response: {:?}", function_name, e))
+}
+
 //Event helper funciton
 fn event_target_value(ev: &web_sys::Event) -> String {
     ev.target()
@@ -93,6 +155,13 @@ fn event_target_value(ev: &web_sys::Event) -> String {
         .unwrap_or_default()
 }
 
+fn event_select_value(ev: &web_sys::Event) -> String {
+    ev.target()
+        .and_then(|t| t.dyn_into::<web_sys::HtmlSelectElement>().ok())
+        .map(|select| select.value())
+        .unwrap_or_default()
+}
+
 // Fetch Silk Spot Price
 async fn fetch_silk_spot_price() -> Result<String, String> {
     if let Ok(js_func) = call_js_function("fetchSilkPrice") {
@@ -258,6 +327,22 @@ fn start_price_refresh(set_prices: WriteSignal<HashMap<String, String>>) {
     closure.forget(); 
 }
 
+// Tick the clock every second so proposal countdowns stay live
+fn start_clock(set_now: WriteSignal<Timestamp>) {
+    let closure = Closure::wrap(Box::new(move || {
+        set_now.set(Timestamp::now());
+    }) as Box<dyn Fn()>);
+
+    if let Some(window) = window() {
+        let _ = window.set_interval_with_callback_and_timeout_and_arguments_0(
+            closure.as_ref().unchecked_ref(),
+            1_000,
+        );
+    }
+
+    closure.forget();
+}
+
 // Auto Fetch SILK Spot every 5 mins
 fn start_silk_spot_refresh(
     silk_spot_price: ReadSignal<String>,
@@ -297,16 +382,14 @@ fn start_silk_spot_refresh(
     closure.forget();
 }
 
-// Call specific keplr functions
-async fn get_wallet_address() -> Option<String> {
-    if let Ok(js_func) = call_js_function("get_wallet_address") {
-        if let Ok(promise) = js_func.call0(&web_sys::window().unwrap()).and_then(|val| val.dyn_into::<Promise>()) {
-            if let Ok(js_value) = wasm_bindgen_futures::JsFuture::from(promise).await {
-                return js_value.as_string();
-            }
+// Show or hide the static wallet error modal from index.html
+fn set_wallet_error_modal(visible: bool) {
+    if let Some(document) = web_sys::window().and_then(|w| w.document()) {
+        if let Some(modal) = document.get_element_by_id("wallet-error-modal") {
+            let style = if visible { "display: flex;" } else { "display: none;" };
+            modal.set_attribute("style", style).ok();
         }
     }
-    None
 }
 
 fn disconnect_keplr_wallet() {
@@ -315,9 +398,31 @@ fn disconnect_keplr_wallet() {
     }
 }
 
-async fn fetch_governance_proposals() -> Result<Vec<GovernanceProposal>, String> {
+// Ask the wallet for the address of each configured chain
+async fn fetch_multi_chain_addresses(
+    provider: &dyn WalletProvider,
+    chains: &[ChainConfig],
+) -> Vec<(ChainConfig, ConnectionStatus)> {
+    let mut addr_list = vec![];
+    for chain in chains {
+        let status = match wallet::connect(provider, &chain.chain_id).await {
+            Ok(addr) => ConnectionStatus::Connected(addr),
+            Err(err) => {
+                log::warn!("❌ Failed to get address for chain {}: {}", chain.chain_id, err);
+                ConnectionStatus::Error
+            }
+        };
+        addr_list.push((chain.clone(), status));
+    }
+    addr_list
+}
+
+async fn fetch_governance_proposals(chain: &ChainConfig) -> Result<Vec<GovernanceProposal>, String> {
     if let Ok(js_func) = call_js_function("fetchGovernanceProposals") {
-        if let Ok(promise) = js_func.call0(&web_sys::window().unwrap()).and_then(|val| val.dyn_into::<Promise>()) {
+        if let Ok(promise) = js_func
+            .call1(&web_sys::window().unwrap(), &JsValue::from_str(&chain.lcd))
+            .and_then(|val| val.dyn_into::<Promise>())
+        {
             match wasm_bindgen_futures::JsFuture::from(promise).await {
                 Ok(result) => {
                     // log::info!("Raw governance proposals JSON: {:?}", result);
@@ -345,97 +450,436 @@ async fn fetch_governance_proposals() -> Result<Vec<GovernanceProposal>, String>
     }
 }
 
+async fn fetch_governance_proposal(chain: &ChainConfig, proposal_id: u64) -> Result<GovernanceProposal, String> {
+    let proposal: GovernanceProposal = call_js_async(
+        "fetchGovernanceProposal",
+        &[JsValue::from_str(&chain.lcd), JsValue::from_str(&proposal_id.to_string())],
+    )
+    .await?;
+    enrich_proposals(vec![proposal]).pop().ok_or_else(|| "Empty proposal".to_string())
+}
+
+// The v1beta1 content of a legacy proposal wrapped in a gov v1 MsgExecLegacyContent
+fn legacy_content(messages: &[serde_json::Value]) -> Option<ProposalContent> {
+    messages
+        .iter()
+        .find(|msg| msg.get("@type").and_then(|t| t.as_str()) == Some("/cosmos.gov.v1.MsgExecLegacyContent"))
+        .and_then(|msg| serde_json::from_value(msg.get("content")?.clone()).ok())
+}
+
+// Title for proposals that carry none, from the type of their first message
+fn infer_title(messages: &[serde_json::Value]) -> String {
+    let Some(first_message) = messages.first() else {
+        return "Untitled Proposal".to_string();
+    };
+    let msg_type = first_message.get("@type").and_then(|t| t.as_str()).unwrap_or("Unknown Type");
+    let base_title = messages::message_label(msg_type).to_string();
+    if msg_type == "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade" {
+        // Software upgrades are named after their plan
+        let version = first_message
+            .get("plan")
+            .and_then(|plan| plan.get("name"))
+            .and_then(|name| name.as_str())
+            .unwrap_or("Unknown");
+        format!("{} {}", base_title, version)
+    } else {
+        base_title
+    }
+}
+
+// Gov v1 proposals carry their own title and summary. Legacy ones keep them in `content`, either
+// directly (v1beta1) or inside a MsgExecLegacyContent, and v1 proposals from before titles were
+// on-chain may have them in inline JSON metadata. Only proposals with none of these get a title
+// inferred from their messages.
 fn enrich_proposals(proposals: Vec<GovernanceProposal>) -> Vec<GovernanceProposal> {
     proposals
         .into_iter()
         .map(|mut proposal| {
-            if proposal.content.is_none() {
-                if let Some(messages) = &proposal.messages {
-                    let first_message = messages.get(0).unwrap_or(&serde_json::Value::Null);
-
-                    // Extract @type and map to user-friendly label
-                    let msg_type = first_message
-                        .get("@type")
-                        .and_then(|t| t.as_str())
-                        .unwrap_or("Unknown Type");
-
-                    let inferred_title = if msg_type == "/cosmos.gov.v1.MsgExecLegacyContent" {
-                        // Extract title from content for MsgExecLegacyContent
-                        first_message
-                            .get("content")
-                            .and_then(|content| content.get("title"))
-                            .and_then(|title| title.as_str())
-                            .unwrap_or("Untitled Proposal")
-                            .to_string()
-                    } else if msg_type == "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade" {
-                        // Extract name for software upgrades
-                        let base_title = map_message_type(msg_type).to_string();
-                        let version = first_message
-                            .get("plan")
-                            .and_then(|plan| plan.get("name"))
-                            .and_then(|name| name.as_str())
-                            .unwrap_or("Unknown");
-                        format!("{} {}", base_title, version)
-                    } else {
-                        // Use mapped message type for other cases
-                        map_message_type(msg_type).to_string()
-                    };
-
-                    let inferred_description = if msg_type == "/cosmos.gov.v1.MsgExecLegacyContent" {
-                        first_message
-                            .get("content")
-                            .and_then(|content| content.get("description"))
-                            .and_then(|desc| desc.as_str())
-                            .unwrap_or("No description available.")
-                            .to_string()
-                    } else {
-                        // Use empty description for all non-legacy proposals
-                        "".to_string()
-                    };
-
-                    proposal.content = Some(ProposalContent {
-                        title: Some(inferred_title),
-                        description: Some(inferred_description),
-                    });
-                }
+            let messages = proposal.messages.clone().unwrap_or_default();
+            let legacy = proposal.content.clone().or_else(|| legacy_content(&messages));
+            let inline = match metadata::parse(proposal.metadata.as_deref().unwrap_or_default()) {
+                MetadataSource::Inline(inline) => Some(inline),
+                _ => None,
+            };
+            let non_empty = |value: Option<String>| value.filter(|v| !v.trim().is_empty());
+
+            if proposal.title.trim().is_empty() {
+                proposal.title = non_empty(legacy.as_ref().and_then(|c| c.title.clone()))
+                    .or_else(|| non_empty(inline.as_ref().and_then(|m| m.title.clone())))
+                    .unwrap_or_else(|| infer_title(&messages));
+            }
+            if proposal.summary.trim().is_empty() {
+                proposal.summary = non_empty(legacy.and_then(|c| c.description))
+                    .or_else(|| non_empty(inline.and_then(|m| m.summary)))
+                    .unwrap_or_default();
             }
             proposal
         })
         .collect()
 }
 
-fn map_message_type(msg_type: &str) -> &str {
-    if msg_type.ends_with("MsgUpdateParams") {
-        "Update Parameters"
-    } else if msg_type.ends_with("MsgSoftwareUpgrade") {
-        "Software Upgrade"
-    } else if msg_type.ends_with("MsgExecLegacyContent") {
-        "Legacy Content"
-    } else if msg_type.ends_with("MsgRecoverClient") {
-        "Recover Client"
+// Calendar entry for a proposal's voting deadline, if it is still being voted on
+fn deadline_event(proposal: &GovernanceProposal, chain: &ChainConfig) -> Option<ical::DeadlineEvent> {
+    if proposal.status != ProposalStatus::VotingPeriod {
+        return None;
+    }
+    let proposal_id = proposal.id?;
+    let end = proposal.voting_end_time?;
+    Some(ical::DeadlineEvent {
+        proposal_id,
+        chain_id: chain.chain_id.clone(),
+        title: proposal.title.clone(),
+        url: proposal_share_url(&chain.chain_id, proposal_id),
+        end_ms: end.millis() as f64,
+    })
+}
+
+// Off-chain metadata of a proposal: authors, forum link and the details the summary leaves out
+fn render_metadata(cx: Scope, meta: ProposalMetadata, summary: &str) -> View {
+    let forum = meta.proposal_forum_url.as_deref().and_then(markdown::safe_url);
+    let details = meta.details.filter(|d| !d.trim().is_empty() && d.trim() != summary.trim());
+    view! { cx,
+        <div class="proposal-metadata">
+            <h3>"Metadata"</h3>
+            <table class="message-fields">
+                {(!meta.authors.is_empty()).then(|| view! { cx, <tr><th>"Authors"</th><td>{meta.authors.join(", ")}</td></tr> })}
+                {forum.map(|url| view! { cx,
+                    <tr><th>"Forum"</th><td><a href={url.clone()} target="_blank" rel="noopener noreferrer">{url}</a></td></tr>
+                })}
+                {meta.vote_option_context.filter(|c| !c.trim().is_empty()).map(|context| view! { cx,
+                    <tr><th>"Vote options"</th><td>{context}</td></tr>
+                })}
+            </table>
+            {details.map(|details| view! { cx, <Markdown source=details /> })}
+        </div>
+    }
+    .into_view(cx)
+}
+
+// Shareable proposal links look like "#proposal/123" on the primary chain and
+// "#proposal/cosmoshub-4/123" elsewhere
+const PROPOSAL_LINK_PREFIX: &str = "#proposal/";
+
+fn proposal_link(chain_id: &str, proposal_id: u64) -> String {
+    if chain_id == chains::PRIMARY_CHAIN_ID {
+        format!("{}{}", PROPOSAL_LINK_PREFIX, proposal_id)
     } else {
-        "Unknown Proposal Type"
+        format!("{}{}/{}", PROPOSAL_LINK_PREFIX, chain_id, proposal_id)
     }
 }
 
+// (chain id, proposal id) from a location hash
+fn proposal_from_hash(hash: &str) -> Option<(String, u64)> {
+    let path = hash.strip_prefix(PROPOSAL_LINK_PREFIX)?.trim_end_matches('/');
+    match path.rsplit_once('/') {
+        Some((chain_id, id)) => Some((chain_id.to_string(), id.parse().ok()?)),
+        None => Some((chains::PRIMARY_CHAIN_ID.to_string(), path.parse().ok()?)),
+    }
+}
+
+// Full URL of a proposal's detail view, for sharing
+fn proposal_share_url(chain_id: &str, proposal_id: u64) -> String {
+    let base = web_sys::window()
+        .and_then(|w| w.location().href().ok())
+        .map(|href| href.split('#').next().unwrap_or_default().to_string())
+        .unwrap_or_default();
+    format!("{}{}", base, proposal_link(chain_id, proposal_id))
+}
+
+
+const PROPOSALS_PER_PAGE: usize = 10;
+
+// Friendly type of a proposal's first message; proposals without messages are legacy content
+fn proposal_type(proposal: &GovernanceProposal) -> &str {
+    proposal
+        .messages
+        .as_ref()
+        .and_then(|messages| messages.first())
+        .and_then(|msg| msg.get("@type"))
+        .and_then(|t| t.as_str())
+        .map(messages::message_label)
+        .unwrap_or("Legacy Content")
+}
+
+// Filters set in the Vote view; empty fields match everything
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ProposalFilter {
+    status: String,
+    message_type: String,
+    search: String,
+    submitted_from: String,
+    submitted_to: String,
+}
+
+impl ProposalFilter {
+    fn matches(&self, proposal: &GovernanceProposal) -> bool {
+        if !self.status.is_empty() && proposal.status.proto_name() != self.status {
+            return false;
+        }
+        if !self.message_type.is_empty() && proposal_type(proposal) != self.message_type {
+            return false;
+        }
+
+        let search = self.search.trim().to_lowercase();
+        if !search.is_empty() {
+            let text = format!("{} {}", proposal.title, proposal.summary).to_lowercase();
+            let id_matches = proposal.id.map(|id| id.to_string()) == Some(search.trim_start_matches('#').to_string());
+            if !id_matches && !text.contains(&search) {
+                return false;
+            }
+        }
+
+        // Dates from <input type="date"> are YYYY-MM-DD, which compares directly with the UTC submission date
+        if !self.submitted_from.is_empty() || !self.submitted_to.is_empty() {
+            let Some(submitted) = proposal.submit_time.map(|t| t.utc_date()) else {
+                return false;
+            };
+            if !self.submitted_from.is_empty() && submitted < self.submitted_from {
+                return false;
+            }
+            if !self.submitted_to.is_empty() && submitted > self.submitted_to {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// Inline status line for a transaction started from the app
+fn render_tx_state(cx: Scope, state: &TxState, chain: &ChainConfig) -> View {
+    match state {
+        TxState::Pending => view! { cx, <p class="tx-state pending">"⏳ Waiting for signature and broadcast..."</p> }.into_view(cx),
+        TxState::Failed(err) => view! { cx, <p class="tx-state failed">{format!("❌ {}", err)}</p> }.into_view(cx),
+        TxState::Done(tx) => {
+            let link = chain.tx_url(&tx.tx_hash);
+            let (class, outcome) = if tx.succeeded() {
+                ("tx-state success", "✅ Success".to_string())
+            } else {
+                ("tx-state failed", format!("❌ Failed (code {}): {}", tx.code, tx.raw_log))
+            };
+            view! { cx,
+                <p class={class}>
+                    {outcome}
+                    " · "
+                    {match link {
+                        Some(link) => view! { cx,
+                            <a href={link} target="_blank" rel="noopener noreferrer">{tx.tx_hash.clone()}</a>
+                        }.into_view(cx),
+                        None => tx.tx_hash.clone().into_view(cx),
+                    }}
+                </p>
+            }.into_view(cx)
+        }
+    }
+}
+
+// One progress bar with a marker at the rule's threshold
+fn render_tally_bar(cx: Scope, label: &str, value: f64, target: f64, class: &'static str, outcome: String) -> View {
+    let width = format!("width: {:.2}%", (value * 100.0).clamp(0.0, 100.0));
+    let marker = format!("left: {:.2}%", (target * 100.0).clamp(0.0, 100.0));
+    view! { cx,
+        <div class="tally-row">
+            <span class="tally-label">{format!("{} {} / {}", label, tally::format_percent(value), tally::format_percent(target))}</span>
+            <div class="tally-bar">
+                <div class={format!("tally-fill {}", class)} style=width></div>
+                <div class="tally-marker" style=marker></div>
+            </div>
+            <span class="tally-outcome">{outcome}</span>
+        </div>
+    }
+    .into_view(cx)
+}
+
+// Which tally render_tally shows, for its heading and verdict. An ended proposal carries its
+// status: bonded stake has moved since voting ended, so the rules can't be re-checked today.
+#[derive(Debug, Clone, PartialEq)]
+enum TallyKind {
+    Live,
+    Final(ProposalStatus),
+    Simulated,
+}
+
+// Vote breakdown plus quorum, threshold and veto bars once the gov params are known
+fn render_tally(
+    cx: Scope,
+    tally: &TallyResult,
+    context: Option<&TallyContext>,
+    kind: TallyKind,
+    expedited: bool,
+    chain: &ChainConfig,
+) -> View {
+    let total = tally.total();
+    let options = [
+        ("Yes", tally.yes(), "yes"),
+        ("No", tally.no(), "no"),
+        ("Abstain", tally.abstain(), "abstain"),
+        ("No With Veto", tally.veto(), "veto"),
+    ]
+    .into_iter()
+    .map(|(label, votes, class)| {
+        let share = if total > 0.0 { votes / total } else { 0.0 };
+        let amount = account::format_amount(votes, &chain.denom, chain);
+        view! { cx,
+            <span class={format!("tally-option {}", class)}>{format!("{} {} ({})", label, tally::format_percent(share), amount)}</span>
+        }
+    })
+    .collect::<Vec<_>>();
+
+    let outcome = match &kind {
+        TallyKind::Final(status) => Some(view! { cx,
+            <p class={format!("tally-verdict vote-status {}", status.css_class())}>{format!("Outcome: {}", status.label())}</p>
+        }),
+        _ => None,
+    };
+    let bars = context.filter(|_| outcome.is_none()).map(|context| {
+        let progress = TallyProgress::measure(tally, context, expedited);
+        let verdict = match (&kind, progress.would_pass()) {
+            (TallyKind::Simulated, true) => "Would pass",
+            (TallyKind::Simulated, false) => "Would not pass",
+            (_, true) => "Currently passing",
+            (_, false) => "Currently not passing",
+        };
+        view! { cx,
+            <div class="tally-bars">
+                {render_tally_bar(
+                    cx, "Turnout", progress.turnout, progress.quorum, "turnout",
+                    if progress.reaches_quorum() { "Quorum reached".to_string() } else { "Below quorum".to_string() },
+                )}
+                {render_tally_bar(
+                    cx, "Yes", progress.yes_share, progress.threshold, "yes",
+                    if progress.yes_share > progress.threshold { "Above threshold".to_string() } else { "Below threshold".to_string() },
+                )}
+                {render_tally_bar(
+                    cx, "Veto", progress.veto_share, progress.veto_threshold, "veto",
+                    if progress.vetoed() { "Vetoed".to_string() } else { "Under veto threshold".to_string() },
+                )}
+                <p class="tally-verdict">{verdict}</p>
+            </div>
+        }
+    });
+
+    view! { cx,
+        <div class="tally">
+            <p class="tally-heading">{match kind {
+                TallyKind::Live => "Current tally",
+                TallyKind::Final(_) => "Final tally",
+                TallyKind::Simulated => "Simulated tally",
+            }}</p>
+            <div class="tally-options">{options}</div>
+            {bars}
+            {outcome}
+        </div>
+    }
+    .into_view(cx)
+}
+
+// "Your vote: Yes", or how the account's validators voted on its behalf
+fn render_account_vote(cx: Scope, status: &AccountVoteStatus, monikers: &HashMap<String, String>) -> View {
+    match &status.own_vote {
+        ValidatorVote::Voted(vote) => {
+            return view! { cx,
+                <p class="account-vote voted">"Your vote: " <strong>{vote.describe()}</strong></p>
+            }
+            .into_view(cx);
+        }
+        ValidatorVote::Unknown(err) => {
+            return view! { cx,
+                <p class="account-vote" title={format!("Lookup failed: {}", err)}>"Your vote: " <strong>"Unknown"</strong></p>
+            }
+            .into_view(cx);
+        }
+        ValidatorVote::NotVoted => {}
+    }
+
+    let validator_votes = status
+        .validator_votes
+        .iter()
+        .map(|(valoper, vote)| {
+            let name = monikers.get(valoper).cloned().unwrap_or_else(|| valoper.clone());
+            let title = match vote {
+                ValidatorVote::Unknown(err) => format!("Lookup failed: {}", err),
+                _ => String::new(),
+            };
+            view! { cx, <li title=title>{name}": "{vote.describe()}</li> }
+        })
+        .collect::<Vec<_>>();
+
+    view! { cx,
+        <div class="account-vote">
+            <p>"You have not voted yet."</p>
+            {(!validator_votes.is_empty()).then(|| view! { cx,
+                <div>
+                    <p>"Your validators will vote on your behalf unless you vote:"</p>
+                    <ul class="validator-votes">{validator_votes}</ul>
+                </div>
+            })}
+        </div>
+    }
+    .into_view(cx)
+}
+
 // The main app component
 #[component]
 pub fn App(cx: Scope) -> impl IntoView {
-    let (is_connected, set_connected) = create_signal(cx, false);
-    let (wallet_address, set_wallet_address) = create_signal(cx, String::new());
+    let connection = create_rw_signal(cx, Connection::Disconnected);
+    let is_connected = create_memo(cx, move |_| matches!(connection.get(), Connection::Wallet(_)));
     let (selected_section, set_selected_section) = create_signal(cx, "Home".to_string());
     let (prices, set_prices) = create_signal(cx, HashMap::new());
     let (governance_proposals, set_governance_proposals) = create_signal(cx, Vec::<GovernanceProposal>::new());
-    let (proposals_fetched, set_proposals_fetched) = create_signal(cx, false);
+    let (gov_chain, set_gov_chain) = create_signal(cx, chains::primary_chain());
+    let (now, set_now) = create_signal(cx, Timestamp::now());
+    let (time_zone, set_time_zone) = create_signal(cx, time::load_time_zone());
     let (liquidation_price, set_liquidation_price) = create_signal(cx, 1.0_f64); // Default price is 1
     let (result, set_result) = create_signal(cx, String::new());
     let (exchange_rate, set_exchange_rate) = create_signal(cx, 1.0_f64);
     let (default_exchange_rate, set_default_exchange_rate) = create_signal(cx, 1.0_f64);
     let (redemption_rates, set_redemption_rates) = create_signal(cx, HashMap::<String, f64>::new());
+    let chain_configs = create_rw_signal(cx, chains::load_chain_configs());
     let (multi_chain_addresses, set_multi_chain_addresses) = create_signal(
         cx,
-        vec![("".to_string(), "".to_string()); 4], // Initialize with empty addresses
-    );    
+        chains::disconnected_addresses(&chain_configs.get_untracked()),
+    );
+    let (chain_to_add, set_chain_to_add) = create_signal(cx, String::new());
+    let (wallet_kind, set_wallet_kind) = create_signal(cx, None::<WalletKind>);
+    let (show_connect_dialog, set_show_connect_dialog) = create_signal(cx, false);
+    let watched_address = create_memo(cx, move |_| match connection.get() {
+        Connection::Watching(entry) => Some(entry),
+        _ => None,
+    });
+    let watchlist = create_rw_signal(cx, watchlist::load_watchlist());
+    let (watch_input, set_watch_input) = create_signal(cx, String::new());
+    let (watch_label_input, set_watch_label_input) = create_signal(cx, String::new());
+    let (watch_error, set_watch_error) = create_signal(cx, None::<String>);
+    let (account_summary, set_account_summary) = create_signal(cx, None::<Result<AccountSummary, String>>);
+    let (converter_input, set_converter_input) = create_signal(cx, String::new());
+    let vote_txs = create_rw_signal(cx, HashMap::<u64, TxState>::new());
+    let (split_vote_proposal, set_split_vote_proposal) = create_signal(cx, None::<u64>);
+    let split_inputs = create_rw_signal(cx, HashMap::<VoteOption, String>::new());
+    let (split_preview, set_split_preview) = create_signal(cx, None::<Vec<WeightedVoteOption>>);
+    let (split_error, set_split_error) = create_signal(cx, None::<String>);
+    let account_votes = create_rw_signal(cx, HashMap::<u64, AccountVoteStatus>::new());
+    let validator_monikers = create_rw_signal(cx, HashMap::<String, String>::new());
+    let (awaiting_vote_only, set_awaiting_vote_only) = create_signal(cx, false);
+    let (group_by_state, set_group_by_state) = create_signal(cx, false);
+    let (tally_context, set_tally_context) = create_signal(cx, None::<TallyContext>);
+    let live_tallies = create_rw_signal(cx, HashMap::<u64, TallyResult>::new());
+    let proposal_filter = create_rw_signal(cx, ProposalFilter::default());
+    let (proposal_page, set_proposal_page) = create_signal(cx, 0_usize);
+    let (block_timing, set_block_timing) = create_signal(cx, None::<BlockTiming>);
+    let (deposit_params, set_deposit_params) = create_signal(cx, None::<DepositParams>);
+    let proposal_deposits = create_rw_signal(cx, HashMap::<u64, Vec<Deposit>>::new());
+    let deposit_inputs = create_rw_signal(cx, HashMap::<u64, String>::new());
+    let deposit_txs = create_rw_signal(cx, HashMap::<u64, TxState>::new());
+    let gov_delegations = create_rw_signal(cx, Vec::<String>::new());
+    let (selected_proposal_id, set_selected_proposal_id) = create_signal(cx, None::<u64>);
+    let (detail_proposal, set_detail_proposal) = create_signal(cx, None::<Result<GovernanceProposal, String>>);
+    let (history_address, set_history_address) = create_signal(cx, String::new());
+    let (ipfs_gateway, set_ipfs_gateway) = create_signal(cx, metadata::load_gateway());
+    let (proposal_metadata, set_proposal_metadata) = create_signal(cx, None::<Result<ProposalMetadata, String>>);
+    let vote_history = create_rw_signal(cx, None::<Result<VoteHistory, String>>);
+    let reference_history = create_rw_signal(cx, HashMap::<u64, Vote>::new());
+    let (proposal_changes, set_proposal_changes) = create_signal(cx, ProposalChanges::default());
+    let (unseen_count, set_unseen_count) = create_signal(cx, 0_usize);
     let (selected_derivative, set_selected_derivative) = create_signal(cx, "stkd-SCRT".to_string());
     let ordered_keys = create_rw_signal(cx, vec![
         "WBTC.axl", "WETH", "SHD", "SCRT", "ATOM", "TIA", "AMBER", "FINA"]);
@@ -443,6 +887,50 @@ pub fn App(cx: Scope) -> impl IntoView {
     let (silk_spot_price, set_silk_spot_price) = create_signal(cx, String::from("No Data"));
     let (api_fetched, set_api_fetched) = create_signal(cx, false);
     
+    // "#proposal/123" opens that proposal's detail view, on load and on back/forward navigation
+    let open_from_hash = move || {
+        let hash = web_sys::window().and_then(|w| w.location().hash().ok()).unwrap_or_default();
+        let Some((chain_id, proposal_id)) = proposal_from_hash(&hash) else {
+            return;
+        };
+        let Some(chain) = chains::find_chain(&chain_id).filter(|chain| chain.governance) else {
+            log::warn!("❌ Unknown chain in proposal link: {}", chain_id);
+            return;
+        };
+        if gov_chain.get_untracked().chain_id != chain.chain_id {
+            set_gov_chain.set(chain);
+        }
+        set_selected_proposal_id.set(Some(proposal_id));
+        set_selected_section.set("Proposal".to_string());
+    };
+    open_from_hash();
+    if let Some(window) = web_sys::window() {
+        let listener = Closure::wrap(Box::new(open_from_hash) as Box<dyn Fn()>);
+        if let Err(err) = window.add_event_listener_with_callback("hashchange", listener.as_ref().unchecked_ref()) {
+            log::error!("❌ Failed to listen for hash changes: {:?}", err);
+        }
+        listener.forget();
+    }
+
+    // Drop the proposal link from the URL once another section is shown
+    create_effect(cx, move |_| {
+        if selected_section.get() != "Proposal" {
+            if let Some(window) = web_sys::window() {
+                let hash = window.location().hash().unwrap_or_default();
+                if proposal_from_hash(&hash).is_some() {
+                    let url = format!(
+                        "{}{}",
+                        window.location().pathname().unwrap_or_default(),
+                        window.location().search().unwrap_or_default()
+                    );
+                    if let Ok(history) = window.history() {
+                        history.replace_state_with_url(&JsValue::NULL, "", Some(&url)).ok();
+                    }
+                }
+            }
+        }
+    });
+
     // Fetch prices on page load
     create_effect(cx, move |_| {
         spawn_local(async move {
@@ -471,6 +959,7 @@ pub fn App(cx: Scope) -> impl IntoView {
    
     // Start auto-refreshing prices every 5 minutes
     start_price_refresh(set_prices);
+    start_clock(set_now);
 
     // Start auto-refreshing SILK Spot Price every 5 minutes
     start_silk_spot_refresh(silk_spot_price, set_silk_spot_price); 
@@ -576,104 +1065,77 @@ pub fn App(cx: Scope) -> impl IntoView {
         });
     });
     
-    // Keplr Functions
-    let connect_wallet = move |_| {
-        log::info!("🚀 Connecting to wallet...");
-    
-        // Check if Keplr is installed
-        let is_keplr_installed = web_sys::window()
-            .and_then(|w| w.get("keplr"))
-            .is_some();
-    
-        if !is_keplr_installed {
-            log::warn!("❌ Wallet not found! Please install Keplr or Fina wallet.");
-    
-            // Show error modal
-            if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-                if let Some(modal) = document.get_element_by_id("wallet-error-modal") {
-                    modal.set_attribute("style", "display: flex;").ok();
-                }
-            }
-    
-            set_connected.set(false);
-            set_wallet_address.set("Not Connected".to_string());
-    
-            // Ensure placeholder multi-chain addresses include asset names
-            set_multi_chain_addresses.set(vec![
-                ("ATOM".to_string(), "Not Connected".to_string()),
-                ("TIA".to_string(), "Not Connected".to_string()),
-                ("OSMO".to_string(), "Not Connected".to_string()),
-                ("NOBLE".to_string(), "Not Connected".to_string()),
-            ]);
+    // Wallet Functions
+    let connect_with = move |kind: WalletKind| {
+        log::info!("🚀 Connecting to {}...", kind.name());
+        set_show_connect_dialog.set(false);
+
+        if !kind.is_installed() {
+            log::warn!("❌ {} not found!", kind.name());
+            set_wallet_error_modal(true);
+
+            connection.set(Connection::Disconnected);
+            set_multi_chain_addresses.set(chains::disconnected_addresses(&chain_configs.get_untracked()));
             return;
         }
-    
+
         // Hide error modal since wallet is installed
-        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-            if let Some(modal) = document.get_element_by_id("wallet-error-modal") {
-                modal.set_attribute("style", "display: none;").ok();
-            }
-        }
-    
+        set_wallet_error_modal(false);
+        set_wallet_kind.set(Some(kind));
+        wallet::expose_active_signer(kind);
+        wallet::remember_wallet(kind);
+
         // Attempt wallet connection
         spawn_local(async move {
-            if let Some(address) = get_wallet_address().await {
-                log::info!("✅ Successfully connected. SCRT address: {}", address);
-                set_wallet_address.set(address);
-    
-                // Fetch multi-chain addresses
-                let chains = vec![
-                    ("cosmoshub-4", "ATOM"),
-                    ("celestia", "TIA"),
-                    ("osmosis-1", "OSMO"),
-                    ("noble-1", "NOBLE"),
-                ];
-    
-                let mut addr_list = vec![];
-                for (chain_id, name) in chains {
-                    match getAddressForMultiChain(chain_id).await {
-                        Ok(js_value) => {
-                            let addr = js_value.as_string().unwrap_or("Error fetching address".to_string());
-                            addr_list.push((name.to_string(), addr));
-                        }
-                        Err(_) => addr_list.push((name.to_string(), "Error fetching address".to_string())),
-                    }
+            let provider = kind.provider();
+            match wallet::connect(provider, chains::PRIMARY_CHAIN_ID).await {
+                Ok(address) => {
+                    log::info!("✅ Successfully connected. SCRT address: {}", address);
+                    connection.set(Connection::Wallet(address));
+
+                    // Fetch multi-chain addresses
+                    let addr_list = fetch_multi_chain_addresses(provider, &chain_configs.get_untracked()).await;
+                    set_multi_chain_addresses.set(addr_list);
+                }
+                Err(err) => {
+                    log::warn!("❌ Failed to fetch SCRT address: {}", err);
+                    connection.set(Connection::Disconnected);
+                    set_multi_chain_addresses.set(chains::disconnected_addresses(&chain_configs.get_untracked()));
                 }
-                set_multi_chain_addresses.set(addr_list);
-            } else {
-                log::warn!("❌ Failed to fetch SCRT address.");
-                set_wallet_address.set("Error fetching SCRT address".to_string());
-    
-                // Populate placeholders for multi-chain addresses
-                set_multi_chain_addresses.set(vec![
-                    ("ATOM".to_string(), "Not Connected".to_string()),
-                    ("TIA".to_string(), "Not Connected".to_string()),
-                    ("OSMO".to_string(), "Not Connected".to_string()),
-                    ("NOBLE".to_string(), "Not Connected".to_string()),
-                ]);
             }
         });
-    };     
+    };
+
+    // Reconnect with the chosen wallet, or let the user pick one
+    let connect_wallet = move |_| {
+        match wallet_kind.get_untracked() {
+            Some(kind) => connect_with(kind),
+            None if WalletKind::ALL.iter().any(|kind| kind.is_installed()) => set_show_connect_dialog.set(true),
+            None => connect_with(WalletKind::Keplr),
+        }
+    };
     
-    // Show modal in "Vote" view if wallet isn't connected and cannot connect
+    // Silently reconnect to the wallet used on the last visit
+    if let Some(kind) = wallet::remembered_wallet().filter(|kind| kind.is_installed()) {
+        log::info!("🔄 Reconnecting to {} from last visit", kind.name());
+        connect_with(kind);
+    }
+
+    // Re-derive every address when the user switches accounts in their wallet; while watching,
+    // that happens once watching stops
+    wallet::listen_for_account_changes(move |kind| {
+        if wallet_kind.get_untracked() == Some(kind) && watched_address.get_untracked().is_none() {
+            log::info!("🔄 {} account changed. Refreshing addresses...", kind.name());
+            connect_with(kind);
+        }
+    });
+
+    // Connect the wallet when the Vote view opens without one; a watched address needs no wallet
     create_effect(cx, move |_| {
-        if selected_section.get().as_str() == "Vote" {
-            if !is_connected.get() {
-                // Attempt to connect wallet before showing error
-                spawn_local(async move {
-                    if let Some(address) = get_wallet_address().await {
-                        log::info!("✅ Wallet connected successfully: {}", address);
-                        set_connected.set(true);
-                        set_wallet_address.set(address);
-                    } else {
-                        log::warn!("❌ Wallet connection failed. Showing error modal.");
-                        if let Some(document) = web_sys::window().and_then(|w| w.document()) {
-                            if let Some(modal) = document.get_element_by_id("wallet-error-modal") {
-                                modal.set_attribute("style", "display: flex;").ok();
-                            }
-                        }
-                    }
-                });
+        if selected_section.get().as_str() == "Vote" && !is_connected.get() && watched_address.get().is_none() {
+            match wallet_kind.get_untracked() {
+                Some(kind) => connect_with(kind),
+                None => connect_wallet(()),
             }
         }
     });
@@ -684,29 +1146,616 @@ pub fn App(cx: Scope) -> impl IntoView {
 
     let disconnect_wallet = move |_| {
         disconnect_keplr_wallet();
-        set_connected.set(false);
-        set_wallet_address.set(String::new());
+        wallet::forget_wallet();
+        set_wallet_kind.set(None);
+        connection.set(Connection::Disconnected);
         // Clear all multi-chain addresses
-        set_multi_chain_addresses.set(vec![
-            ("ATOM".to_string(), "Not Connected".to_string()),
-            ("TIA".to_string(), "Not Connected".to_string()),
-            ("OSMO".to_string(), "Not Connected".to_string()),
-            ("NOBLE".to_string(), "Not Connected".to_string()),
-        ]);
+        set_multi_chain_addresses.set(chains::disconnected_addresses(&chain_configs.get_untracked()));
     };    
 
-    //Fetch Governance Proposals
+    // The address every account query runs against: a watched address wins over the wallet's
+    let active_account = create_memo(cx, move |_| match connection.get() {
+        Connection::Disconnected => None,
+        Connection::Wallet(address) => Some(ActiveAccount { address, chain: chains::primary_chain(), watch_only: false }),
+        Connection::Watching(watched) => watchlist::validate_watch_address(&watched.address).ok().map(|chain| ActiveAccount {
+            address: watched.address,
+            chain,
+            watch_only: true,
+        }),
+    });
+
+    // Watched addresses get their multi-chain addresses derived locally instead of from the wallet
+    let displayed_chain_addresses = create_memo(cx, move |_| match active_account.get() {
+        Some(account) if account.watch_only => {
+            chains::derive_watch_addresses(&account.address, &account.chain, &chain_configs.get())
+        }
+        _ => multi_chain_addresses.get(),
+    });
+
+    // Refresh balances and staking whenever the active account changes
     create_effect(cx, move |_| {
-        if selected_section.get().as_str() == "Vote" && !proposals_fetched.get() {
-            set_proposals_fetched(true);
+        set_account_summary.set(None);
+        if let Some(account) = active_account.get() {
             spawn_local(async move {
-                match fetch_governance_proposals().await {
-                    Ok(proposals) => set_governance_proposals.set(proposals),
-                    Err(err) => log::error!("❌ Error fetching governance proposals: {}", err),
+                let summary = account::fetch_account_summary(&account.chain, &account.address).await;
+                if let Err(err) = &summary {
+                    log::error!("❌ Error fetching account summary: {}", err);
+                }
+                // Ignore results for an account that is no longer active
+                if active_account.get_untracked().map(|a| a.address) == Some(account.address) {
+                    set_account_summary.set(Some(summary));
                 }
             });
         }
-    });    
+    });
+
+    // The account's address on the chain whose proposals are shown
+    let gov_address = create_memo(cx, move |_| {
+        let chain = gov_chain.get();
+        if let Some(account) = active_account.get() {
+            if account.chain.chain_id == chain.chain_id {
+                return Some(account.address);
+            }
+        }
+        displayed_chain_addresses
+            .get()
+            .into_iter()
+            .find(|(c, _)| c.chain_id == chain.chain_id)
+            .and_then(|(_, status)| match status {
+                ConnectionStatus::Connected(address) => Some(address),
+                _ => None,
+            })
+    });
+
+    // Whether the wallet's signer signs direct, which any chain accepts for gov messages
+    let wallet_signs_direct = create_memo(cx, move |_| wallet_kind.get().is_some_and(|kind| kind.provider().signs_direct()));
+
+    // Look up the account's vote (or its validators' votes) on one proposal
+    let refresh_account_vote = move |proposal_id: u64| {
+        let Some(address) = gov_address.get_untracked() else {
+            return;
+        };
+        let chain = gov_chain.get_untracked();
+        let validators = gov_delegations.get_untracked();
+        spawn_local(async move {
+            match governance::fetch_account_vote_status(&chain, proposal_id, &address, &validators).await {
+                Ok(status) => {
+                    if gov_address.get_untracked() == Some(address) {
+                        account_votes.update(|votes| {
+                            votes.insert(proposal_id, status);
+                        });
+                    }
+                }
+                Err(err) => log::error!("❌ Error fetching vote on proposal #{}: {}", proposal_id, err),
+            }
+        });
+    };
+
+    // Load the account's delegations on the governance chain, then its votes on every open proposal.
+    // Only a new account or chain starts over; proposals loaded later look up their votes themselves.
+    create_effect(cx, move |_| {
+        account_votes.set(HashMap::new());
+        gov_delegations.set(Vec::new());
+        let Some(address) = gov_address.get() else {
+            return;
+        };
+        let chain = gov_chain.get_untracked();
+        spawn_local(async move {
+            match account::fetch_account_summary(&chain, &address).await {
+                Ok(summary) => gov_delegations.set(summary.delegations.into_iter().map(|d| d.validator_address).collect()),
+                Err(err) => log::error!("❌ Error fetching delegations of {}: {}", address, err),
+            }
+            if gov_address.get_untracked() != Some(address) {
+                return;
+            }
+            let open: Vec<u64> = governance_proposals.with_untracked(|proposals| {
+                proposals
+                    .iter()
+                    .filter(|p| p.status == ProposalStatus::VotingPeriod)
+                    .filter_map(|p| p.id)
+                    .collect()
+            });
+            open.into_iter().for_each(refresh_account_vote);
+        });
+    });
+
+    // Addresses whose voting history can be shown: the wallet's and watched ones on the governance chain
+    let history_addresses = create_memo(cx, move |_| {
+        let chain = gov_chain.get();
+        let mut addresses: Vec<(String, String)> =
+            gov_address.get().map(|address| ("Connected wallet".to_string(), address)).into_iter().collect();
+        for entry in watchlist.get() {
+            let on_chain = watchlist::validate_watch_address(&entry.address).is_ok_and(|c| c.chain_id == chain.chain_id);
+            if on_chain && !addresses.iter().any(|(_, a)| *a == entry.address) {
+                addresses.push((entry.label, entry.address));
+            }
+        }
+        addresses
+    });
+    let selected_history_address = create_memo(cx, move |_| {
+        let addresses = history_addresses.get();
+        let wanted = history_address.get();
+        addresses
+            .iter()
+            .find(|(_, a)| *a == wanted)
+            .or(addresses.first())
+            .map(|(_, a)| a.clone())
+    });
+    let history_open = create_memo(cx, move |_| selected_section.get() == "History");
+
+    // Load the selected address' votes when the History view opens
+    create_effect(cx, move |_| {
+        vote_history.set(None);
+        if !history_open.get() {
+            return;
+        }
+        let Some(address) = selected_history_address.get() else {
+            return;
+        };
+        let chain = gov_chain.get();
+        spawn_local(async move {
+            let votes = governance::fetch_vote_history(&chain, &address).await;
+            if let Err(err) = &votes {
+                log::error!("❌ Error fetching vote history of {}: {}", address, err);
+            }
+            if selected_history_address.get_untracked() == Some(address) {
+                vote_history.set(Some(votes));
+            }
+        });
+    });
+
+    // YoloNode's votes, to compare against; the validator only runs on the primary chain
+    create_effect(cx, move |_| {
+        reference_history.set(HashMap::new());
+        let chain = gov_chain.get();
+        if !history_open.get() || chain.chain_id != chains::PRIMARY_CHAIN_ID {
+            return;
+        }
+        spawn_local(async move {
+            match governance::fetch_validator_vote_history(&chain, governance::YOLONODE_VALIDATOR).await {
+                Ok(history) => reference_history.set(history.votes),
+                Err(err) => log::error!("❌ Error fetching YoloNode vote history: {}", err),
+            }
+        });
+    });
+
+    // Proposals left after the Vote view's filters, newest first
+    let filtered_proposals = create_memo(cx, move |_| {
+        let filter = proposal_filter.get();
+        let awaiting_only = awaiting_vote_only.get() && gov_address.get().is_some();
+        // Vote lookups only matter to the awaiting-vote filter, so they don't re-render the list otherwise
+        let votes = if awaiting_only { account_votes.get() } else { HashMap::new() };
+        let mut proposals = governance_proposals
+            .get()
+            .into_iter()
+            .filter(|proposal| filter.matches(proposal))
+            .filter(|proposal| {
+                !awaiting_only
+                    || (proposal.status == ProposalStatus::VotingPeriod
+                        && proposal.id.and_then(|id| votes.get(&id)).is_some_and(|v| v.awaits_vote()))
+            })
+            .collect::<Vec<_>>();
+        // Stable, so proposals stay newest first within each state
+        if group_by_state.get() {
+            proposals.sort_by_key(|proposal| proposal.status.rank());
+        }
+        proposals
+    });
+    let page_count = move || filtered_proposals.with(|p| p.len().div_ceil(PROPOSALS_PER_PAGE)).max(1);
+
+    // Any filter change starts again from the first page
+    let update_filter = move |update: &dyn Fn(&mut ProposalFilter)| {
+        proposal_filter.update(|filter| update(filter));
+        set_proposal_page.set(0);
+    };
+
+    // Why the vote and deposit buttons are disabled, if they are
+    let signing_disabled_reason = create_memo(cx, move |_| {
+        if watched_address.get().is_some() {
+            Some("Watching an address (read-only). Stop watching to sign with your wallet.")
+        } else if !is_connected.get() || wallet_kind.get().is_none() {
+            Some("Connect a wallet to vote or deposit.")
+        } else if gov_address.get().is_none() {
+            Some("Your wallet has no address on this chain. Add the chain on the Wallet view.")
+        } else if !wallet_signs_direct.get() && gov_chain.get().chain_id != chains::PRIMARY_CHAIN_ID {
+            // The amino names secretjs gives gov messages are only known to be accepted on Secret Network
+            Some("This wallet only signs amino, which works on Secret Network only. Update Cosmostation to vote or deposit here.")
+        } else {
+            None
+        }
+    });
+
+    // Proposal submissions follow the same rules and need a direct signer on every chain
+    let proposal_sign_unavailable = create_memo(cx, move |_| {
+        signing_disabled_reason.get().or_else(|| {
+            (!wallet_signs_direct.get())
+                .then_some("This wallet only signs amino, which proposal submissions can't use. Update Cosmostation to sign them here.")
+        })
+    });
+
+    // The proposal builder is re-created only when the chain changes, so wallet changes keep the draft
+    let builder_chain = create_memo(cx, move |_| gov_chain.get());
+
+    let refresh_deposits = move |proposal_id: u64| {
+        let chain = gov_chain.get_untracked();
+        spawn_local(async move {
+            match governance::fetch_deposits(&chain, proposal_id).await {
+                Ok(deposits) => proposal_deposits.update(|all| {
+                    all.insert(proposal_id, deposits);
+                }),
+                Err(err) => log::error!("❌ Error fetching deposits of proposal #{}: {}", proposal_id, err),
+            }
+        });
+    };
+
+    // Reload one proposal in place, e.g. after a deposit moved it into its voting period
+    let refresh_proposal = move |proposal_id: u64| {
+        let chain = gov_chain.get_untracked();
+        spawn_local(async move {
+            match fetch_governance_proposal(&chain, proposal_id).await {
+                Ok(updated) => {
+                    let voting = updated.status == ProposalStatus::VotingPeriod;
+                    set_governance_proposals.update(|proposals| {
+                        if let Some(proposal) = proposals.iter_mut().find(|p| p.id == Some(proposal_id)) {
+                            *proposal = updated;
+                        }
+                    });
+                    if voting {
+                        refresh_account_vote(proposal_id);
+                    }
+                }
+                Err(err) => log::error!("❌ Error refreshing proposal #{}: {}", proposal_id, err),
+            }
+        });
+        refresh_deposits(proposal_id);
+    };
+
+    let make_deposit = move |proposal_id: u64| {
+        let chain = gov_chain.get_untracked();
+        let input = deposit_inputs.get_untracked().get(&proposal_id).cloned().unwrap_or_default();
+        let amount = match account::parse_amount(&input, &chain) {
+            Ok(amount) => amount,
+            Err(err) => {
+                deposit_txs.update(|txs| {
+                    txs.insert(proposal_id, TxState::Failed(err));
+                });
+                return;
+            }
+        };
+        log::info!("💰 Depositing {} {} on proposal #{}", input.trim(), chain.symbol, proposal_id);
+        deposit_txs.update(|txs| {
+            txs.insert(proposal_id, TxState::Pending);
+        });
+        spawn_local(async move {
+            let state = match governance::deposit_on_proposal(&chain, proposal_id, amount).await {
+                Ok(tx) => {
+                    if tx.succeeded() {
+                        deposit_inputs.update(|inputs| {
+                            inputs.remove(&proposal_id);
+                        });
+                        refresh_proposal(proposal_id);
+                    }
+                    TxState::Done(tx)
+                }
+                Err(err) => {
+                    log::error!("❌ Deposit on proposal #{} failed: {}", proposal_id, err);
+                    TxState::Failed(err)
+                }
+            };
+            deposit_txs.update(|txs| {
+                txs.insert(proposal_id, state);
+            });
+        });
+    };
+
+    // Opening the Vote view marks everything as seen; highlights stay until the next visit
+    create_effect(cx, move |_| {
+        if selected_section.get() == "Vote" {
+            governance_proposals.with(|proposals| {
+                if !proposals.is_empty() {
+                    let statuses: Vec<(u64, &ProposalStatus)> = proposals.iter().filter_map(|p| Some((p.id?, &p.status))).collect();
+                    seen::mark_seen(&gov_chain.get_untracked().chain_id, &statuses);
+                    set_unseen_count.set(0);
+                }
+            });
+        }
+    });
+
+    // Load the proposal shown in the detail view, from the list when it is already there
+    create_effect(cx, move |_| {
+        if selected_section.get() != "Proposal" {
+            return;
+        }
+        let Some(proposal_id) = selected_proposal_id.get() else {
+            return;
+        };
+        let chain = gov_chain.get();
+        if let Some(proposal) = governance_proposals.get_untracked().into_iter().find(|p| p.id == Some(proposal_id)) {
+            set_detail_proposal.set(Some(Ok(proposal)));
+            return;
+        }
+        set_detail_proposal.set(None);
+        spawn_local(async move {
+            let proposal = fetch_governance_proposal(&chain, proposal_id).await;
+            if let Err(err) = &proposal {
+                log::error!("❌ Error fetching proposal #{}: {}", proposal_id, err);
+            }
+            if let Ok(proposal) = &proposal {
+                if proposal.status == ProposalStatus::VotingPeriod {
+                    match tally::fetch_live_tally(&chain, proposal_id).await {
+                        Ok(tally) => live_tallies.update(|tallies| {
+                            tallies.insert(proposal_id, tally);
+                        }),
+                        Err(err) => log::error!("❌ Error fetching tally of proposal #{}: {}", proposal_id, err),
+                    }
+                }
+                if tally_context.get_untracked().is_none() {
+                    if let Ok(context) = tally::fetch_tally_context(&chain).await {
+                        set_tally_context.set(Some(context));
+                    }
+                }
+                if block_timing.get_untracked().is_none() {
+                    if let Ok(timing) = messages::fetch_block_timing(&chain).await {
+                        set_block_timing.set(Some(timing));
+                    }
+                }
+            }
+            if selected_proposal_id.get_untracked() == Some(proposal_id) {
+                set_detail_proposal.set(Some(proposal));
+            }
+        });
+    });
+
+    // Metadata of the proposal in the detail view, fetched through the IPFS gateway when it points there
+    create_effect(cx, move |_| {
+        set_proposal_metadata.set(None);
+        let Some(Ok(proposal)) = detail_proposal.get() else {
+            return;
+        };
+        let source = metadata::parse(proposal.metadata.as_deref().unwrap_or_default());
+        if let MetadataSource::Inline(inline) = source {
+            set_proposal_metadata.set(Some(Ok(inline)));
+            return;
+        }
+        let Some(url) = metadata::document_url(&source, &ipfs_gateway.get()) else {
+            return;
+        };
+        spawn_local(async move {
+            let fetched = metadata::fetch_metadata(&url).await;
+            if let Err(err) = &fetched {
+                log::error!("❌ Error fetching proposal metadata from {}: {}", url, err);
+            }
+            if detail_proposal.get_untracked().and_then(|p| p.ok()).and_then(|p| p.metadata) == proposal.metadata {
+                set_proposal_metadata.set(Some(fetched));
+            }
+        });
+    });
+
+    let cast_vote = move |proposal_id: u64, option: VoteOption| {
+        let chain = gov_chain.get_untracked();
+        log::info!("🗳️ Voting {} on {} proposal #{}", option.label(), chain.chain_id, proposal_id);
+        vote_txs.update(|txs| {
+            txs.insert(proposal_id, TxState::Pending);
+        });
+        spawn_local(async move {
+            let state = match governance::vote_on_proposal(&chain, proposal_id, option).await {
+                Ok(tx) => {
+                    if tx.succeeded() {
+                        refresh_account_vote(proposal_id);
+                    }
+                    TxState::Done(tx)
+                }
+                Err(err) => {
+                    log::error!("❌ Vote on proposal #{} failed: {}", proposal_id, err);
+                    TxState::Failed(err)
+                }
+            };
+            vote_txs.update(|txs| {
+                txs.insert(proposal_id, state);
+            });
+        });
+    };
+
+    let open_split_editor = move |proposal_id: u64| {
+        split_inputs.set(HashMap::new());
+        set_split_preview.set(None);
+        set_split_error.set(None);
+        set_split_vote_proposal.set(Some(proposal_id));
+    };
+
+    // Validate the split and show it for confirmation before anything is signed
+    let preview_split_vote = move |_| {
+        let inputs = split_inputs.get_untracked();
+        let inputs: Vec<(VoteOption, String)> = VoteOption::ALL
+            .iter()
+            .map(|option| (*option, inputs.get(option).cloned().unwrap_or_default()))
+            .collect();
+        match governance::build_weighted_options(&inputs) {
+            Ok(options) => {
+                set_split_error.set(None);
+                set_split_preview.set(Some(options));
+            }
+            Err(err) => {
+                set_split_preview.set(None);
+                set_split_error.set(Some(err));
+            }
+        }
+    };
+
+    let cast_weighted_vote = move |proposal_id: u64, options: Vec<WeightedVoteOption>| {
+        let chain = gov_chain.get_untracked();
+        log::info!("🗳️ Weighted vote on proposal #{}: {}", proposal_id, governance::describe_split(&options));
+        set_split_vote_proposal.set(None);
+        vote_txs.update(|txs| {
+            txs.insert(proposal_id, TxState::Pending);
+        });
+        spawn_local(async move {
+            let state = match governance::vote_weighted_on_proposal(&chain, proposal_id, &options).await {
+                Ok(tx) => {
+                    if tx.succeeded() {
+                        refresh_account_vote(proposal_id);
+                    }
+                    TxState::Done(tx)
+                }
+                Err(err) => {
+                    log::error!("❌ Weighted vote on proposal #{} failed: {}", proposal_id, err);
+                    TxState::Failed(err)
+                }
+            };
+            vote_txs.update(|txs| {
+                txs.insert(proposal_id, state);
+            });
+        });
+    };
+
+    let watch_address = move |entry: WatchedAddress| {
+        match watchlist::validate_watch_address(&entry.address) {
+            Ok(chain) => {
+                log::info!("👀 Watching {} on {}", entry.address, chain.chain_id);
+                set_watch_error.set(None);
+                watchlist.update(|list| watchlist::remember(list, entry.clone()));
+                connection.set(Connection::Watching(entry));
+            }
+            Err(err) => set_watch_error.set(Some(err)),
+        }
+    };
+
+    // Go back to the wallet, if one was connected before watching
+    let stop_watching = move || {
+        connection.set(Connection::Disconnected);
+        if let Some(kind) = wallet_kind.get_untracked() {
+            connect_with(kind);
+        }
+    };
+
+    let watch_from_inputs = move |_| {
+        let address = watch_input.get_untracked().trim().to_string();
+        let label = match watch_label_input.get_untracked().trim() {
+            "" if address.is_ascii() && address.len() > 14 => {
+                format!("{}…{}", &address[..10], &address[address.len() - 4..])
+            }
+            "" => address.clone(),
+            label => label.to_string(),
+        };
+        watch_address(WatchedAddress { address, label });
+        set_watch_input.set(String::new());
+        set_watch_label_input.set(String::new());
+    };
+
+    // Add a chain from the bundled registry and derive its address if connected
+    let add_chain = move |_| {
+        let chain_id = chain_to_add.get_untracked();
+        let Some(chain) = chains::find_chain(&chain_id) else {
+            log::warn!("❌ Unknown chain selected: {}", chain_id);
+            return;
+        };
+
+        chain_configs.update(|configs| configs.push(chain.clone()));
+        chains::save_chain_configs(&chain_configs.get_untracked());
+        set_chain_to_add.set(String::new());
+
+        if let (true, Some(kind)) = (is_connected.get_untracked(), wallet_kind.get_untracked()) {
+            spawn_local(async move {
+                let mut added = fetch_multi_chain_addresses(kind.provider(), &[chain]).await;
+                set_multi_chain_addresses.update(|addresses| addresses.append(&mut added));
+            });
+        } else {
+            set_multi_chain_addresses.update(|addresses| {
+                addresses.push((chain, ConnectionStatus::NotConnected))
+            });
+        }
+    };
+
+    let remove_chain = move |chain_id: String| {
+        chain_configs.update(|configs| configs.retain(|c| c.chain_id != chain_id));
+        chains::save_chain_configs(&chain_configs.get_untracked());
+        set_multi_chain_addresses.update(|addresses| addresses.retain(|(c, _)| c.chain_id != chain_id));
+    };
+
+    //Fetch Governance Proposals on load and whenever another chain is picked, so the Vote button
+    //can badge what changed since the last visit
+    create_effect(cx, move |_| {
+        let chain = gov_chain.get();
+        set_governance_proposals.set(Vec::new());
+        live_tallies.set(HashMap::new());
+        proposal_deposits.set(HashMap::new());
+        validator_monikers.set(HashMap::new());
+        vote_txs.set(HashMap::new());
+        deposit_txs.set(HashMap::new());
+        set_tally_context.set(None);
+        set_deposit_params.set(None);
+        set_block_timing.set(None);
+        set_proposal_changes.set(ProposalChanges::default());
+        set_unseen_count.set(0);
+        set_proposal_page.set(0);
+
+        // Results for a chain that is no longer selected are dropped
+        let still_selected = {
+            let chain_id = chain.chain_id.clone();
+            move || gov_chain.get_untracked().chain_id == chain_id
+        };
+        spawn_local(async move {
+            match fetch_governance_proposals(&chain).await {
+                Ok(proposals) => {
+                    if !still_selected() {
+                        return;
+                    }
+                    let open: Vec<u64> = proposals
+                        .iter()
+                        .filter(|p| p.status == ProposalStatus::VotingPeriod)
+                        .filter_map(|p| p.id)
+                        .collect();
+                    let awaiting_deposit: Vec<u64> = proposals
+                        .iter()
+                        .filter(|p| p.status == ProposalStatus::DepositPeriod)
+                        .filter_map(|p| p.id)
+                        .collect();
+                    let statuses: Vec<(u64, &ProposalStatus)> = proposals.iter().filter_map(|p| Some((p.id?, &p.status))).collect();
+                    let changes = seen::changes_since(seen::load_seen(&chain.chain_id).as_ref(), &statuses);
+                    set_unseen_count.set(changes.count());
+                    set_proposal_changes.set(changes);
+                    set_governance_proposals.set(proposals);
+                    for proposal_id in awaiting_deposit {
+                        refresh_deposits(proposal_id);
+                    }
+                    open.iter().copied().for_each(refresh_account_vote);
+                    for proposal_id in open {
+                        match tally::fetch_live_tally(&chain, proposal_id).await {
+                            Ok(tally) if still_selected() => live_tallies.update(|tallies| {
+                                tallies.insert(proposal_id, tally);
+                            }),
+                            Ok(_) => return,
+                            Err(err) => log::error!("❌ Error fetching tally of proposal #{}: {}", proposal_id, err),
+                        }
+                    }
+                }
+                Err(err) => log::error!("❌ Error fetching governance proposals: {}", err),
+            }
+            match governance::fetch_deposit_params(&chain).await {
+                Ok(params) if still_selected() => set_deposit_params.set(Some(params)),
+                Ok(_) => return,
+                Err(err) => log::error!("❌ Error fetching gov deposit params: {}", err),
+            }
+            match messages::fetch_block_timing(&chain).await {
+                Ok(timing) if still_selected() => set_block_timing.set(Some(timing)),
+                Ok(_) => return,
+                Err(err) => log::error!("❌ Error fetching block timing: {}", err),
+            }
+            match tally::fetch_tally_context(&chain).await {
+                Ok(context) if still_selected() => set_tally_context.set(Some(context)),
+                Ok(_) => return,
+                Err(err) => log::error!("❌ Error fetching gov tally params: {}", err),
+            }
+            // Monikers label the validator votes shown for accounts that haven't voted
+            match governance::fetch_validators(&chain.lcd).await {
+                Ok(validators) if still_selected() => validator_monikers.set(
+                    validators.into_iter().map(|v| (v.operator_address, v.moniker)).collect(),
+                ),
+                Ok(_) => {}
+                Err(err) => log::error!("❌ Error fetching validators: {}", err),
+            }
+        });
+    });
 
     //Selected derivative signal
     create_effect(cx, move |_| {
@@ -796,7 +1845,12 @@ pub fn App(cx: Scope) -> impl IntoView {
                         connect_wallet(());
                         set_selected_section.set("Wallet".to_string());
                     }>"Wallet"</button>
-                    <button class="link-button" on:click=move |_| set_selected_section.set("Vote".to_string())>"Vote"</button>
+                    <button class="link-button" on:click=move |_| set_selected_section.set("Vote".to_string())>
+                        "Vote"
+                        {move || (unseen_count.get() > 0).then(|| view! { cx,
+                            <span class="nav-badge" title="New proposals and status changes since your last visit">{unseen_count.get()}</span>
+                        })}
+                    </button>
                     <button class="link-button" on:click=move |_| set_selected_section.set("API".to_string())>"API"</button>
                 </div>
                 <img src="/static/YoloNode-Logo-cropped.png" alt="YoloNode Logo" class="logo" />
@@ -1121,12 +2175,6 @@ pub fn App(cx: Scope) -> impl IntoView {
                         <div class="wallet-section-header">
                             <h2>"Wallet Info : "</h2>
                             {move || {
-                                if wallet_address.get() != "Not Connected" && !wallet_address.get().is_empty() {
-                                    set_connected.set(true);
-                                } else {
-                                    set_connected.set(false);
-                                }
-                
                                 if is_connected.get() {
                                     view! { cx,
                                         <button class="link-button" on:click=disconnect_wallet>"Disconnect Wallet"</button>
@@ -1148,150 +2196,582 @@ pub fn App(cx: Scope) -> impl IntoView {
                                 }}
                                 <span class="wallet-address-label">"SCRT :"</span>
                             </h3>
-                            {move || {
-                                let addr = wallet_address.get();
-                                if addr == "Not Connected" || addr == "Error fetching SCRT address" || addr.is_empty() {
-                                    view! { cx, <span class="wallet-address">"Not Connected"</span> }
-                                } else {
-                                    view! { cx, <span class="wallet-address">{addr.clone()}</span> }
-                                }
+                            {move || match connection.get() {
+                                Connection::Wallet(addr) => view! { cx, <span class="wallet-address">{addr}</span> },
+                                _ => view! { cx, <span class="wallet-address">"Not Connected"</span> },
                             }}
                         </div>
                 
                         <div class="multi-chain-addresses">
-                            {move || multi_chain_addresses.get().iter().map(|(name, addr)| {
-                                let icon_path = icon_map.get().get(name.as_str()).cloned();  // Ensuring string lookup
+                            {move || displayed_chain_addresses.get().into_iter().map(|(chain, status)| {
+                                let chain_id = chain.chain_id.clone();
                 
                                 view! {
                                     cx,
                                     <div class="wallet-address-display">
                                         <h3>
-                                            {match icon_path {
+                                            {match chain.icon {
                                                 Some(icon) => view! { cx, <img src={icon} class="token-icon" /> }.into_view(cx),
                                                 None => "".into_view(cx),  // Fixing mismatched return types
                                             }}
-                                            <span class="wallet-address-label">{format!("{} :", name)}</span>
+                                            <span class="wallet-address-label" title={chain.chain_name}>{format!("{} :", chain.symbol)}</span>
                                         </h3>
-                                        <span class="wallet-address">{addr.clone()}</span>
+                                        <span class={match status {
+                                            ConnectionStatus::Error | ConnectionStatus::CoinTypeMismatch => "wallet-address error",
+                                            _ => "wallet-address",
+                                        }}>{status.to_string()}</span>
+                                        <button class="remove-chain-button" title="Remove chain" on:click=move |_| remove_chain(chain_id.clone())>"✕"</button>
                                     </div>
                                 }
                             }).collect::<Vec<_>>()}
                         </div>
+
+                        <div class="add-chain-row">
+                            <select
+                                class="chain-select"
+                                on:change=move |ev| {
+                                    if let Some(target) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlSelectElement>().ok()) {
+                                        set_chain_to_add.set(target.value());
+                                    }
+                                }
+                                prop:value=move || chain_to_add.get()
+                            >
+                                <option value="">"Add a chain..."</option>
+                                {move || chains::available_chains(&chain_configs.get()).into_iter().map(|chain| {
+                                    view! { cx,
+                                        <option value={chain.chain_id.clone()}>{format!("{} ({})", chain.chain_name, chain.chain_id)}</option>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </select>
+                            <button
+                                class="link-button"
+                                prop:disabled=move || chain_to_add.get().is_empty()
+                                on:click=add_chain
+                            >"Add Chain"</button>
+                        </div>
+
+                        // Watch-only addresses
+                        <div class="wallet-section-header">
+                            <h2>"Watch Address : "</h2>
+                            {move || watched_address.get().map(|_| view! { cx,
+                                <button class="link-button" on:click=move |_| stop_watching()>"Stop Watching"</button>
+                            })}
+                        </div>
+                        <hr class="gold-line" />
+                        <div class="watch-row">
+                            <input
+                                class="watch-input"
+                                type="text"
+                                placeholder="secret1... or any supported address"
+                                prop:value=move || watch_input.get()
+                                on:input=move |ev| set_watch_input.set(event_target_value(&ev))
+                            />
+                            <input
+                                class="watch-input label"
+                                type="text"
+                                placeholder="Label (optional)"
+                                prop:value=move || watch_label_input.get()
+                                on:input=move |ev| set_watch_label_input.set(event_target_value(&ev))
+                            />
+                            <button class="link-button" on:click=watch_from_inputs>"Watch"</button>
+                        </div>
+                        {move || watch_error.get().map(|err| view! { cx, <p class="watch-error">{err}</p> })}
+                        <div class="watchlist">
+                            {move || watchlist.get().into_iter().map(|entry| {
+                                let is_active = watched_address.get().map(|w| w.address == entry.address).unwrap_or(false);
+                                let address = entry.address.clone();
+                                let title = entry.address.clone();
+                                let label = entry.label.clone();
+                                view! { cx,
+                                    <span class={if is_active { "watch-chip active" } else { "watch-chip" }}>
+                                        <button class="watch-chip-label" title={title} on:click=move |_| watch_address(entry.clone())>{label}</button>
+                                        <button class="remove-chain-button" title="Forget address" on:click=move |_| {
+                                            watchlist.update(|list| watchlist::forget(list, &address));
+                                            if watched_address.get_untracked().map(|w| w.address == address).unwrap_or(false) {
+                                                stop_watching();
+                                            }
+                                        }>"✕"</button>
+                                    </span>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </div>
+
+                        // Balances and staking for the connected or watched address
+                        {move || active_account.get().map(|account| {
+                            let chain = account.chain.clone();
+                            view! { cx,
+                                <div class="account-summary">
+                                    <div class="wallet-section-header">
+                                        <h2>"Account : "</h2>
+                                    </div>
+                                    <hr class="gold-line" />
+                                    <p class="account-address">
+                                        {if account.watch_only {
+                                            let label = watched_address.get().map(|w| w.label).unwrap_or_default();
+                                            format!("👀 Watching {} (read-only) : {}", label, account.address)
+                                        } else {
+                                            format!("🔑 Connected : {}", account.address)
+                                        }}
+                                    </p>
+                                    {move || match account_summary.get() {
+                                        None => view! { cx, <p>"Loading account..."</p> }.into_view(cx),
+                                        Some(Err(err)) => view! { cx, <p class="watch-error">{format!("Error loading account: {}", err)}</p> }.into_view(cx),
+                                        Some(Ok(summary)) => {
+                                            let chain = chain.clone();
+                                            let staked = account::format_amount(summary.total_staked(&chain), &chain.denom, &chain);
+                                            view! { cx,
+                                                <div class="account-details">
+                                                    <h3>"Balances :"</h3>
+                                                    {if summary.balances.is_empty() {
+                                                        view! { cx, <p>"No balances"</p> }.into_view(cx)
+                                                    } else {
+                                                        summary.balances.iter().map(|coin| view! { cx,
+                                                            <p class="account-line">{account::format_coin(coin, &chain)}</p>
+                                                        }).collect::<Vec<_>>().into_view(cx)
+                                                    }}
+                                                    <h3>{format!("Staked : {}", staked)}</h3>
+                                                    {summary.delegations.iter().map(|delegation| view! { cx,
+                                                        <p class="account-line">
+                                                            {format!("{} : {}", delegation.validator_address, account::format_coin(&delegation.balance, &chain))}
+                                                        </p>
+                                                    }).collect::<Vec<_>>()}
+                                                    <h3>"Pending Rewards :"</h3>
+                                                    {summary.rewards.iter().map(|coin| view! { cx,
+                                                        <p class="account-line">{account::format_coin(coin, &chain)}</p>
+                                                    }).collect::<Vec<_>>()}
+                                                </div>
+                                            }.into_view(cx)
+                                        }
+                                    }}
+                                </div>
+                            }
+                        })}
                     </div>
                 },                                                                                                                                                                                      
                 "Vote" => view! { cx,
                     <div class="vote-section">
-                        <h2>"Governance Proposals :"</h2>
+                        <h2>{move || format!("Governance Proposals — {} :", gov_chain.get().chain_name)}</h2>
                         <hr class="gold-line" />
+                        <div class="proposal-filters">
+                            <select
+                                class="chain-select"
+                                prop:value=move || gov_chain.get().chain_id
+                                on:change=move |ev| {
+                                    let chain_id = event_select_value(&ev);
+                                    if let Some(chain) = chains::find_chain(&chain_id) {
+                                        set_gov_chain.set(chain);
+                                    }
+                                }
+                            >
+                                {move || {
+                                    let primary = chains::primary_chain();
+                                    std::iter::once(primary.clone())
+                                        .chain(chain_configs.get().into_iter().filter(|c| c.chain_id != primary.chain_id && c.governance))
+                                        .map(|chain| view! { cx,
+                                            <option value={chain.chain_id.clone()}>{format!("{} ({})", chain.chain_name, chain.chain_id)}</option>
+                                        })
+                                        .collect::<Vec<_>>()
+                                }}
+                            </select>
+                            <input
+                                type="text"
+                                class="proposal-search"
+                                placeholder="Search title, description or #id"
+                                prop:value=move || proposal_filter.get().search
+                                on:input=move |ev| {
+                                    let value = event_target_value(&ev);
+                                    update_filter(&|filter| filter.search = value.clone());
+                                }
+                            />
+                            <select
+                                class="chain-select"
+                                prop:value=move || proposal_filter.get().status
+                                on:change=move |ev| {
+                                    let value = event_select_value(&ev);
+                                    update_filter(&|filter| filter.status = value.clone());
+                                }
+                            >
+                                <option value="">"All statuses"</option>
+                                {ProposalStatus::KNOWN.iter().map(|status| view! { cx,
+                                    <option value={status.proto_name().to_string()}>{status.label()}</option>
+                                }).collect::<Vec<_>>()}
+                            </select>
+                            <select
+                                class="chain-select"
+                                prop:value=move || proposal_filter.get().message_type
+                                on:change=move |ev| {
+                                    let value = event_select_value(&ev);
+                                    update_filter(&|filter| filter.message_type = value.clone());
+                                }
+                            >
+                                <option value="">"All types"</option>
+                                {move || {
+                                    let mut types: Vec<String> = governance_proposals
+                                        .get()
+                                        .iter()
+                                        .map(|p| proposal_type(p).to_string())
+                                        .collect();
+                                    types.sort();
+                                    types.dedup();
+                                    types.into_iter().map(|t| view! { cx, <option value={t.clone()}>{t}</option> }).collect::<Vec<_>>()
+                                }}
+                            </select>
+                            <label class="date-filter">
+                                "Submitted from "
+                                <input
+                                    type="date"
+                                    prop:value=move || proposal_filter.get().submitted_from
+                                    on:input=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        update_filter(&|filter| filter.submitted_from = value.clone());
+                                    }
+                                />
+                            </label>
+                            <label class="date-filter">
+                                " to "
+                                <input
+                                    type="date"
+                                    prop:value=move || proposal_filter.get().submitted_to
+                                    on:input=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        update_filter(&|filter| filter.submitted_to = value.clone());
+                                    }
+                                />
+                            </label>
+                            <label class="vote-filter">
+                                <input
+                                    type="checkbox"
+                                    prop:checked=awaiting_vote_only
+                                    prop:disabled=move || gov_address.get().is_none()
+                                    on:change=move |ev| {
+                                        set_awaiting_vote_only.set(event_target_checked(&ev));
+                                        set_proposal_page.set(0);
+                                    }
+                                />
+                                " Only proposals awaiting my vote"
+                            </label>
+                            <label class="vote-filter">
+                                <input
+                                    type="checkbox"
+                                    prop:checked=group_by_state
+                                    on:change=move |ev| {
+                                        set_group_by_state.set(event_target_checked(&ev));
+                                        set_proposal_page.set(0);
+                                    }
+                                />
+                                " Group by state"
+                            </label>
+                            <button class="vote-button" on:click=move |_| {
+                                update_filter(&|filter| *filter = ProposalFilter::default());
+                                set_awaiting_vote_only.set(false);
+                            }>"Clear filters"</button>
+                            <button class="vote-button" on:click=move |_| set_selected_section.set("History".to_string())>"📜 Voting history"</button>
+                            <button class="vote-button" on:click=move |_| set_selected_section.set("Propose".to_string())>"📝 New proposal"</button>
+                            <button class="vote-button" title="Switch between local time and UTC" on:click=move |_| {
+                                let zone = time_zone.get_untracked().toggled();
+                                time::save_time_zone(zone);
+                                set_time_zone.set(zone);
+                            }>{move || format!("🕒 {}", time_zone.get().label())}</button>
+                        </div>
+                        {move || {
+                            let chain = gov_chain.get();
+                            let events: Vec<ical::DeadlineEvent> =
+                                governance_proposals.with(|p| p.iter().filter_map(|proposal| deadline_event(proposal, &chain)).collect());
+                            (!events.is_empty()).then(|| view! { cx,
+                                <a
+                                    class="vote-button calendar-link"
+                                    href={ical::data_url(&ical::calendar(&events, js_sys::Date::now()))}
+                                    download={format!("{}-governance-deadlines.ics", chain.chain_id)}
+                                >{format!("📅 Export {} voting deadlines (.ics)", events.len())}</a>
+                            })
+                        }}
+                        <p class="proposal-count">{move || format!(
+                            "{} of {} proposals",
+                            filtered_proposals.with(|p| p.len()),
+                            governance_proposals.with(|p| p.len()),
+                        )}</p>
                         <ul class="vote-list">
                             {move || {
-                                governance_proposals.get().iter().map(|proposal| {
-                                    // Map the status to a user-friendly string
-                                    let display_status = match proposal.status.trim() {
-                                        "PROPOSAL_STATUS_PASSED" => "Proposal Passed",
-                                        "PROPOSAL_STATUS_REJECTED" => "Proposal Rejected",
-                                        "PROPOSAL_STATUS_FAILED" => "Proposal Failed",
-                                        "PROPOSAL_STATUS_VOTING_PERIOD" => "Voting Period",
-                                        "PROPOSAL_STATUS_DEPOSIT_PERIOD" => "Deposit Period",
-                                        _ => "Unknown Status",
+                                let chain = gov_chain.get();
+                                let page = proposal_page.get().min(page_count() - 1);
+                                filtered_proposals.get().iter().skip(page * PROPOSALS_PER_PAGE).take(PROPOSALS_PER_PAGE).map(|proposal| {
+                                    let display_status = proposal.status.label();
+
+                                    // Highlight what is new or changed since the last visit
+                                    let changes = proposal_changes.get_untracked();
+                                    let is_new = proposal.id.is_some_and(|id| changes.new_ids.contains(&id));
+                                    let previous_status = proposal.id.and_then(|id| changes.changed.get(&id).cloned());
+                                    let item_class = if is_new || previous_status.is_some() { "vote-item highlighted" } else { "vote-item" };
+                                    let change_badge = if is_new {
+                                        Some("New".to_string())
+                                    } else {
+                                        previous_status.map(|previous| format!("{} → {}", previous.label(), display_status))
                                     };
                 
-                                    // Expiration text handling for "Passed", "Rejected", and "Failed" proposals
-                                    let expiration_text = if ["PROPOSAL_STATUS_PASSED", "PROPOSAL_STATUS_REJECTED", "PROPOSAL_STATUS_FAILED"].contains(&proposal.status.trim()) {
-                                        proposal.expiration_time.as_ref().map(|time| {
-                                            let date = js_sys::Date::new(&JsValue::from_str(time));
-                                            if date.get_time().is_finite() {
-                                                format!("Ended {}", date.to_locale_date_string("en-US", &JsValue::undefined()))
-                                            } else {
-                                                "Invalid End Date".to_string()
-                                            }
-                                        }).unwrap_or_else(|| "No End Date".to_string())
+                                    // End date of finished proposals, in the chosen time zone
+                                    let voting_end = proposal.voting_end_time;
+                                    let expiration_text = if proposal.status.has_ended() {
+                                        Some(move || match voting_end {
+                                            Some(end) => format!("Ended {}", end.format(time_zone.get())),
+                                            None => "No End Date".to_string(),
+                                        })
                                     } else {
-                                        "".to_string()
+                                        None
                                     };
                 
-                                    // Expiration text handling for "Voting Period" and "Deposit Period"
-                                    let time_expiration_text = if ["PROPOSAL_STATUS_VOTING_PERIOD", "PROPOSAL_STATUS_DEPOSIT_PERIOD"].contains(&proposal.status.trim()) {
-                                        proposal.expiration_time.as_ref().map(|time| {
-                                            let date = js_sys::Date::new(&JsValue::from_str(time));
-                                            if date.get_time().is_finite() {
-                                                let now = js_sys::Date::now();
-                                                let remaining_ms = date.get_time() - now;
-                                                
-                                                if remaining_ms > 0.0 {
-                                                    let remaining_days = (remaining_ms / (1000.0 * 60.0 * 60.0 * 24.0)) as i64;
-                                                    let remaining_hours = ((remaining_ms % (1000.0 * 60.0 * 60.0 * 24.0)) / (1000.0 * 60.0 * 60.0)) as i64;
-                                                    format!("Expires in {}d {}h", remaining_days, remaining_hours)
-                                                } else {
-                                                    "Expired".to_string()
-                                                }
-                                            } else {
-                                                "Invalid Expiration Date".to_string()
-                                            }
-                                        }).unwrap_or_else(|| "No Expiration Date".to_string())
-                                    } else {
-                                        "".to_string()
+                                    // Live countdown to the end of the voting or deposit period
+                                    let period_end = match proposal.status {
+                                        ProposalStatus::VotingPeriod => proposal.voting_end_time,
+                                        _ => proposal.deposit_end_time,
+                                    };
+                                    let time_expiration_text = move || match period_end {
+                                        Some(end) if end > now.get() => format!("Expires in {}", time::format_countdown(end.millis() - now.get().millis())),
+                                        Some(_) => "Expired".to_string(),
+                                        None => "No Expiration Date".to_string(),
                                     };
+                                    let time_expiration_title = move || period_end.map(|end| end.format(time_zone.get())).unwrap_or_default();
                 
                                     // Title and description handling
-                                    let title = proposal
-                                        .content
-                                        .as_ref()
-                                        .and_then(|content| content.title.clone())
-                                        .unwrap_or_else(|| "Untitled Proposal".to_string());
+                                    let title = proposal.title.clone();
+                                    let description = Some(proposal.summary.clone()).filter(|s| !s.trim().is_empty());
                 
-                                    let description = proposal
-                                        .content
-                                        .as_ref()
-                                        .and_then(|content| content.description.clone());
+                                    // Decode each message into a summary card
+                                    let proposal_messages = proposal.messages.clone().unwrap_or_default();
+                                    let details_chain = chain.clone();
+                                    let formatted_details = move || {
+                                        let chain = &details_chain;
+                                        let timing = block_timing.get();
+                                        let context = DecodeContext { chain, block_timing: timing.as_ref(), time_zone: time_zone.get() };
+                                        if proposal_messages.is_empty() {
+                                            return vec![view! { cx, <pre class="formatted-json">"No details available."</pre> }.into_view(cx)];
+                                        }
+                                        proposal_messages
+                                            .iter()
+                                            .map(|msg| view! { cx, <MessageCard message=messages::decode(msg, &context) lcd=chain.lcd.clone() /> }.into_view(cx))
+                                            .collect::<Vec<_>>()
+                                    };
                 
-                                    // Convert proposal details (JSON) into formatted text
-                                    let formatted_details = proposal
-                                        .messages
-                                        .as_ref()
-                                        .map(|messages| {
-                                            messages.iter().map(|msg| {
-                                                let json_pretty = serde_json::to_string_pretty(msg).unwrap_or_else(|_| "Invalid JSON".to_string());
-                                                view! {
-                                                    cx,
-                                                    <pre class="formatted-json">{json_pretty}</pre>
-                                                }
-                                            }).collect::<Vec<_>>()
-                                        })
-                                        .unwrap_or_else(|| vec![view! { cx, <pre class="formatted-json">"No details available."</pre> }]);
+                                    // Deposit progress and form while the proposal still needs deposits
+                                    let total_deposit = proposal.total_deposit.clone().unwrap_or_default();
+                                    let deposit_actions = proposal
+                                        .id
+                                        .filter(|_| proposal.status == ProposalStatus::DepositPeriod)
+                                        .map(|proposal_id| {
+                                            let chain = chain.clone();
+                                            let is_pending = move || matches!(deposit_txs.get().get(&proposal_id), Some(TxState::Pending));
+                                            let deposited = governance::coin_total(&total_deposit, &chain.denom);
+                                            let progress_chain = chain.clone();
+                                            let depositors_chain = chain.clone();
+                                            let tx_chain = chain.clone();
+                                            view! { cx,
+                                                <div class="deposit-actions">
+                                                    {move || {
+                                                        let chain = &progress_chain;
+                                                        let minimum = deposit_params.get().map(|p| governance::coin_total(&p.min_deposit, &chain.denom));
+                                                        let fraction = minimum.filter(|m| *m > 0.0).map(|m| deposited / m).unwrap_or(0.0);
+                                                        view! { cx,
+                                                            <div class="tally-row">
+                                                                <span class="tally-label">{format!(
+                                                                    "Deposited {} / {}",
+                                                                    account::format_amount(deposited, &chain.denom, chain),
+                                                                    minimum.map(|m| account::format_amount(m, &chain.denom, chain)).unwrap_or_else(|| "…".to_string()),
+                                                                )}</span>
+                                                                <div class="tally-bar">
+                                                                    <div class="tally-fill" style={format!("width: {:.2}%", (fraction * 100.0).clamp(0.0, 100.0))}></div>
+                                                                </div>
+                                                                <span class="tally-outcome">{tally::format_percent(fraction)}</span>
+                                                            </div>
+                                                        }
+                                                    }}
+                                                    <div class="deposit-form">
+                                                        <input
+                                                            type="number"
+                                                            min="0"
+                                                            step="any"
+                                                            class="watch-input"
+                                                            placeholder={format!("Amount in {}", chain.symbol)}
+                                                            prop:value=move || deposit_inputs.get().get(&proposal_id).cloned().unwrap_or_default()
+                                                            on:input=move |ev| {
+                                                                let value = event_target_value(&ev);
+                                                                deposit_inputs.update(|inputs| {
+                                                                    inputs.insert(proposal_id, value);
+                                                                });
+                                                            }
+                                                        />
+                                                        <button
+                                                            class="vote-button"
+                                                            prop:disabled=move || signing_disabled_reason.get().is_some() || is_pending()
+                                                            on:click=move |_| make_deposit(proposal_id)
+                                                        >"Deposit"</button>
+                                                    </div>
+                                                    {move || signing_disabled_reason.get().map(|reason| view! { cx,
+                                                        <p class="vote-disabled-reason">{reason}</p>
+                                                    })}
+                                                    {move || deposit_txs.get().get(&proposal_id).map(|state| render_tx_state(cx, state, &tx_chain))}
+                                                    {move || proposal_deposits.get().get(&proposal_id).filter(|d| !d.is_empty()).map(|deposits| {
+                                                        let chain = &depositors_chain;
+                                                        view! { cx,
+                                                            <div class="depositors">
+                                                                <p class="tally-heading">"Depositors"</p>
+                                                                <ul>
+                                                                    {deposits.iter().map(|deposit| view! { cx,
+                                                                        <li>
+                                                                            <span class="account-address">{deposit.depositor.clone()}</span>
+                                                                            ": "
+                                                                            {deposit.amount.iter().map(|coin| account::format_coin(coin, chain)).collect::<Vec<_>>().join(", ")}
+                                                                        </li>
+                                                                    }).collect::<Vec<_>>()}
+                                                                </ul>
+                                                            </div>
+                                                        }
+                                                    })}
+                                                </div>
+                                            }
+                                        });
+
+                                    // Live tally while voting, the final result once it has ended
+                                    let tally_chain = chain.clone();
+                                    let expedited = proposal.expedited.unwrap_or(false);
+                                    let tally_view = match (proposal.id, &proposal.status) {
+                                        (Some(proposal_id), ProposalStatus::VotingPeriod) => Some(view! { cx,
+                                            <div>
+                                                {move || live_tallies.get().get(&proposal_id).map(|tally| {
+                                                    render_tally(cx, tally, tally_context.get().as_ref(), TallyKind::Live, expedited, &tally_chain)
+                                                })}
+                                            </div>
+                                        }),
+                                        (_, status) if status.has_ended() => {
+                                            let status = status.clone();
+                                            proposal.final_tally_result.clone().map(|tally| view! { cx,
+                                                <div>
+                                                    {render_tally(cx, &tally, None, TallyKind::Final(status), false, &tally_chain)}
+                                                </div>
+                                            })
+                                        }
+                                        _ => None,
+                                    };
+
+                                    let calendar_link = deadline_event(proposal, &chain).map(|event| view! { cx,
+                                        <a
+                                            class="read-more calendar-link"
+                                            href={ical::data_url(&ical::calendar(&[event], js_sys::Date::now()))}
+                                            download={format!("proposal-{}.ics", proposal.id.unwrap_or_default())}
+                                        >"📅 Add to calendar"</a>
+                                    });
+
+                                    // Vote buttons for proposals still in their voting period
+                                    let vote_actions = proposal
+                                        .id
+                                        .filter(|_| proposal.status == ProposalStatus::VotingPeriod)
+                                        .map(|proposal_id| {
+                                            let tx_chain = chain.clone();
+                                            let is_pending = move || matches!(vote_txs.get().get(&proposal_id), Some(TxState::Pending));
+                                            view! { cx,
+                                                <div class="vote-actions">
+                                                    {calendar_link}
+                                                    <div class="vote-buttons">
+                                                        {VoteOption::ALL.iter().map(|option| {
+                                                            let option = *option;
+                                                            view! { cx,
+                                                                <button
+                                                                    class={format!("vote-button {}", option.css_class())}
+                                                                    prop:disabled=move || signing_disabled_reason.get().is_some() || is_pending()
+                                                                    on:click=move |_| cast_vote(proposal_id, option)
+                                                                >{option.label()}</button>
+                                                            }
+                                                        }).collect::<Vec<_>>()}
+                                                        <button
+                                                            class="vote-button split"
+                                                            prop:disabled=move || signing_disabled_reason.get().is_some() || is_pending()
+                                                            on:click=move |_| open_split_editor(proposal_id)
+                                                        >"Split Vote..."</button>
+                                                    </div>
+                                                    {move || (split_vote_proposal.get() == Some(proposal_id)).then(|| view! { cx,
+                                                        <div class="split-editor">
+                                                            <p>"Split your voting power across options. Weights must add up to 100%."</p>
+                                                            {VoteOption::ALL.iter().map(|option| {
+                                                                let option = *option;
+                                                                view! { cx,
+                                                                    <div class="split-row">
+                                                                        <label>{option.label()}</label>
+                                                                        <input
+                                                                            type="number"
+                                                                            min="0"
+                                                                            max="100"
+                                                                            step="0.01"
+                                                                            placeholder="0"
+                                                                            prop:value=move || split_inputs.get().get(&option).cloned().unwrap_or_default()
+                                                                            on:input=move |ev| {
+                                                                                let value = event_target_value(&ev);
+                                                                                split_inputs.update(|inputs| {
+                                                                                    inputs.insert(option, value);
+                                                                                });
+                                                                                set_split_preview.set(None);
+                                                                            }
+                                                                        />
+                                                                        <span>"%"</span>
+                                                                    </div>
+                                                                }
+                                                            }).collect::<Vec<_>>()}
+                                                            {move || split_error.get().map(|err| view! { cx, <p class="watch-error">{err}</p> })}
+                                                            {move || match split_preview.get() {
+                                                                Some(options) => {
+                                                                    let summary = governance::describe_split(&options);
+                                                                    view! { cx,
+                                                                        <div class="split-preview">
+                                                                            <p>{format!("You are about to vote {} on proposal #{}.", summary, proposal_id)}</p>
+                                                                            <button class="vote-button" on:click=move |_| cast_weighted_vote(proposal_id, options.clone())>"Sign & Broadcast"</button>
+                                                                            <button class="vote-button" on:click=move |_| set_split_vote_proposal.set(None)>"Cancel"</button>
+                                                                        </div>
+                                                                    }.into_view(cx)
+                                                                }
+                                                                None => view! { cx,
+                                                                    <div class="split-preview">
+                                                                        <button class="vote-button" on:click=preview_split_vote>"Preview"</button>
+                                                                        <button class="vote-button" on:click=move |_| set_split_vote_proposal.set(None)>"Cancel"</button>
+                                                                    </div>
+                                                                }.into_view(cx),
+                                                            }}
+                                                        </div>
+                                                    })}
+                                                    {move || account_votes.get().get(&proposal_id).map(|status| render_account_vote(cx, status, &validator_monikers.get()))}
+                                                    {move || signing_disabled_reason.get().map(|reason| view! { cx,
+                                                        <p class="vote-disabled-reason">{reason}</p>
+                                                    })}
+                                                    {move || vote_txs.get().get(&proposal_id).map(|state| render_tx_state(cx, state, &tx_chain))}
+                                                </div>
+                                            }
+                                        });
                 
                                     view! {
                                         cx,
-                                        <li class="vote-item">
+                                        <li class={item_class}>
+                                            {change_badge.map(|badge| view! { cx, <span class="change-badge">{badge}</span> })}
+                                            {proposal.expedited.unwrap_or(false).then(|| view! { cx, <span class="change-badge expedited-badge">"⚡ Expedited"</span> })}
                                             <h3>
-                                                <span class="proposal-number">{format!("Proposal #{}: ", proposal.id.unwrap_or(0))}</span>
+                                                {match proposal.id {
+                                                    Some(id) => view! { cx,
+                                                        <a class="proposal-number" href={proposal_link(&chain.chain_id, id)}>{format!("Proposal #{}: ", id)}</a>
+                                                    }.into_view(cx),
+                                                    None => view! { cx, <span class="proposal-number">"Proposal (unknown id): "</span> }.into_view(cx),
+                                                }}
                                                 <span class="proposal-title">{title}</span>
                                             </h3>
-                                            {description.as_ref().map(|desc| view! { cx, <p>{desc.clone()}</p> })}
+                                            {description.filter(|desc| !desc.trim().is_empty()).map(|desc| view! { cx, <Markdown source=desc /> })}
                                             <div class="details-section">
                                                 {formatted_details} 
                                             </div>
-                                            <p class={format!(
-                                                "vote-status {}",
-                                                match proposal.status.trim() {
-                                                    "PROPOSAL_STATUS_PASSED" => "passed",
-                                                    "PROPOSAL_STATUS_REJECTED" => "rejected",
-                                                    "PROPOSAL_STATUS_FAILED" => "failed",
-                                                    "PROPOSAL_STATUS_VOTING_PERIOD" => "voting",
-                                                    "PROPOSAL_STATUS_DEPOSIT_PERIOD" => "deposit",
-                                                    _ => "default",
-                                                }
-                                            )}>
+                                            <p class={format!("vote-status {}", proposal.status.css_class())}>
                                                 <span class="status-text">{display_status}</span>
-                                                {if ["PROPOSAL_STATUS_VOTING_PERIOD", "PROPOSAL_STATUS_DEPOSIT_PERIOD"].contains(&proposal.status.trim()) {
+                                                {if proposal.status.is_active() {
                                                     view! {
                                                         cx,
                                                         <span>
                                                             <span class="separator">" | "</span>
-                                                            <span class="expiration-text">{time_expiration_text}</span>
+                                                            <span class="expiration-text" title=time_expiration_title>{time_expiration_text}</span>
                                                         </span>
                                                     }
-                                                } else if !expiration_text.is_empty() {
+                                                } else if let Some(expiration_text) = expiration_text {
                                                     view! {
                                                         cx,
                                                         <span>
@@ -1303,14 +2783,307 @@ pub fn App(cx: Scope) -> impl IntoView {
                                                     view! { cx, <span></span> }
                                                 }}
                                             </p>
+                                            {tally_view}
+                                            {deposit_actions}
+                                            {vote_actions}
                                             <hr class="gold-line" />
                                         </li>
                                     }
                                 }).collect::<Vec<_>>()
                             }}
                         </ul>
+                        <div class="pagination">
+                            <button
+                                class="vote-button"
+                                prop:disabled=move || proposal_page.get() == 0
+                                on:click=move |_| set_proposal_page.update(|page| *page = page.saturating_sub(1))
+                            >"← Newer"</button>
+                            <span>{move || format!("Page {} of {}", proposal_page.get().min(page_count() - 1) + 1, page_count())}</span>
+                            <button
+                                class="vote-button"
+                                prop:disabled={move || proposal_page.get() + 1 >= page_count()}
+                                on:click=move |_| set_proposal_page.update(|page| *page += 1)
+                            >"Older →"</button>
+                        </div>
                     </div>
                 },  
+                "History" => view! { cx,
+                    <div class="vote-section vote-history">
+                        <button class="read-more" on:click=move |_| set_selected_section.set("Vote".to_string())>"← All proposals"</button>
+                        <h2>{move || format!("Voting History — {} :", gov_chain.get().chain_name)}</h2>
+                        <hr class="gold-line" />
+                        {move || {
+                            let addresses = history_addresses.get();
+                            if addresses.is_empty() {
+                                return view! { cx,
+                                    <p>"Connect a wallet or watch an address on this chain to see its voting history."</p>
+                                }.into_view(cx);
+                            }
+                            view! { cx,
+                                <select
+                                    class="chain-select"
+                                    prop:value=move || selected_history_address.get().unwrap_or_default()
+                                    on:change=move |ev| set_history_address.set(event_select_value(&ev))
+                                >
+                                    {addresses.into_iter().map(|(label, address)| view! { cx,
+                                        <option value={address.clone()}>{format!("{} ({})", label, address)}</option>
+                                    }).collect::<Vec<_>>()}
+                                </select>
+                            }.into_view(cx)
+                        }}
+                        {move || {
+                            let scan = match vote_history.get() {
+                                None if selected_history_address.get().is_some() => return view! { cx, <p>"Loading vote history..."</p> }.into_view(cx),
+                                None => return view! { cx, <span></span> }.into_view(cx),
+                                Some(Err(err)) => return view! { cx,
+                                    <p class="watch-error">{format!("Could not load the vote history: {}", err)}</p>
+                                }.into_view(cx),
+                                Some(Ok(scan)) => scan,
+                            };
+                            let truncated_at = scan.truncated_at;
+                            let votes = scan.votes;
+                            let chain = gov_chain.get();
+                            let has_reference = chain.chain_id == chains::PRIMARY_CHAIN_ID;
+                            let reference = reference_history.get();
+
+                            // Every proposal that reached its voting period, newest first
+                            let mut proposals: Vec<GovernanceProposal> = governance_proposals
+                                .get()
+                                .into_iter()
+                                .filter(|p| p.id.is_some())
+                                .filter(|p| p.status == ProposalStatus::VotingPeriod || p.status.has_ended())
+                                .collect();
+                            proposals.sort_by_key(|p| std::cmp::Reverse(p.id));
+
+                            let entries: Vec<history::HistoryEntry> = proposals
+                                .iter()
+                                .map(|p| {
+                                    let id = p.id.unwrap_or_default();
+                                    history::HistoryEntry {
+                                        status: &p.status,
+                                        vote: votes.get(&id),
+                                        reference_vote: reference.get(&id),
+                                    }
+                                })
+                                .collect();
+                            let stats = history::stats(&entries);
+                            let percent = |value: Option<f64>| value.map(tally::format_percent).unwrap_or_else(|| "—".to_string());
+
+                            let rows = proposals.iter().map(|p| {
+                                let id = p.id.unwrap_or_default();
+                                let title = p.title.clone();
+                                let vote = votes.get(&id);
+                                let agreement = match (vote, p.status.passed()) {
+                                    (Some(vote), Some(passed)) => match history::agrees_with_outcome(vote, passed) {
+                                        Some(true) => "✅",
+                                        Some(false) => "❌",
+                                        None => "—",
+                                    },
+                                    _ => "",
+                                };
+                                let reference_vote = has_reference.then(|| view! { cx,
+                                    <td>{reference.get(&id).map(|v| v.describe()).unwrap_or_else(|| "—".to_string())}</td>
+                                });
+                                view! { cx,
+                                    <tr>
+                                        <td><a class="proposal-number" href={proposal_link(&chain.chain_id, id)}>{format!("#{}", id)}</a></td>
+                                        <td>{title}</td>
+                                        <td>{p.status.label()}</td>
+                                        <td class={if vote.is_some() { "" } else { "no-vote" }}>{vote.map(|v| v.describe()).unwrap_or_else(|| "Did not vote".to_string())}</td>
+                                        {reference_vote}
+                                        <td>{agreement}</td>
+                                    </tr>
+                                }
+                            }).collect::<Vec<_>>();
+
+                            view! { cx,
+                                <div>
+                                    {truncated_at.map(|max| view! { cx,
+                                        <p class="watch-error">{format!(
+                                            "Only the newest {} transactions were searched, so older votes may be missing and participation may be understated.",
+                                            max
+                                        )}</p>
+                                    })}
+                                    <div class="history-stats">
+                                        <div class="history-stat">
+                                            <span class="history-stat-value">{percent(stats.participation())}</span>
+                                            <span>{format!("Participation ({} of {} finished proposals)", stats.voted, stats.finished)}</span>
+                                        </div>
+                                        <div class="history-stat">
+                                            <span class="history-stat-value">{percent(stats.outcome_agreement())}</span>
+                                            <span>{format!("Agreed with the outcome ({} of {} Yes/No votes)", stats.agreed_with_outcome, stats.decisive_votes)}</span>
+                                        </div>
+                                        {has_reference.then(|| view! { cx,
+                                            <div class="history-stat">
+                                                <span class="history-stat-value">{percent(stats.reference_agreement())}</span>
+                                                <span>{format!("Voted like YoloNode ({} of {} shared votes)", stats.agreed_with_reference, stats.shared_votes)}</span>
+                                            </div>
+                                        })}
+                                    </div>
+                                    <table class="message-fields history-table">
+                                        <tr>
+                                            <th>"Proposal"</th>
+                                            <th>"Title"</th>
+                                            <th>"Outcome"</th>
+                                            <th>"Vote"</th>
+                                            {has_reference.then(|| view! { cx, <th>"YoloNode"</th> })}
+                                            <th>"Agreed"</th>
+                                        </tr>
+                                        {rows}
+                                    </table>
+                                </div>
+                            }.into_view(cx)
+                        }}
+                    </div>
+                },
+                "Propose" => view! { cx,
+                    <div class="vote-section">
+                        <button class="read-more" on:click=move |_| set_selected_section.set("Vote".to_string())>"← All proposals"</button>
+                        <h2>{move || format!("New Proposal — {} :", gov_chain.get().chain_name)}</h2>
+                        <hr class="gold-line" />
+                        {move || view! { cx, <ProposalBuilder chain=builder_chain.get() proposer=Signal::derive(cx, move || gov_address.get())
+                            sign_unavailable=Signal::derive(cx, move || proposal_sign_unavailable.get()) /> }}
+                    </div>
+                },
+                "Proposal" => view! { cx,
+                    <div class="vote-section proposal-detail">
+                        <button class="read-more" on:click=move |_| set_selected_section.set("Vote".to_string())>"← All proposals"</button>
+                        {move || match (selected_proposal_id.get(), detail_proposal.get()) {
+                            (None, _) => view! { cx, <p>"No proposal selected."</p> }.into_view(cx),
+                            (Some(proposal_id), None) => view! { cx, <p>{format!("Loading proposal #{}...", proposal_id)}</p> }.into_view(cx),
+                            (Some(proposal_id), Some(Err(err))) => view! { cx,
+                                <p class="watch-error">{format!("Could not load proposal #{}: {}", proposal_id, err)}</p>
+                            }.into_view(cx),
+                            (Some(proposal_id), Some(Ok(proposal))) => {
+                                let title = proposal.title.clone();
+                                let description = Some(proposal.summary.clone()).filter(|s| !s.trim().is_empty());
+                                let summary = proposal.summary.clone();
+                                let metadata_source = metadata::parse(proposal.metadata.as_deref().unwrap_or_default());
+                                let is_ipfs = matches!(metadata_source, MetadataSource::Ipfs(_));
+                                let metadata_link = match &metadata_source {
+                                    MetadataSource::Url(url) => markdown::safe_url(url),
+                                    _ => None,
+                                };
+                                let chain = gov_chain.get();
+                                let zone = time_zone.get();
+                                let total_deposit = proposal.total_deposit.as_ref().map(|coins| {
+                                    coins
+                                        .iter()
+                                        .map(|coin| account::format_coin(coin, &chain))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")
+                                });
+                                let rows: Vec<(&str, Option<String>)> = vec![
+                                    ("Status", Some(proposal.status.label())),
+                                    ("Submitted", proposal.submit_time.map(|t| t.format(zone))),
+                                    ("Deposit end", proposal.deposit_end_time.map(|t| t.format(zone))),
+                                    ("Voting start", proposal.voting_start_time.map(|t| t.format(zone))),
+                                    ("Voting end", proposal.voting_end_time.map(|t| t.format(zone))),
+                                    ("Proposer", proposal.proposer.clone()),
+                                    ("Total deposit", total_deposit),
+                                    ("Expedited", proposal.expedited.map(|e| if e { "Yes".to_string() } else { "No".to_string() })),
+                                    ("Failed reason", proposal.failed_reason.clone()),
+                                    ("Metadata", proposal.metadata.clone()),
+                                ];
+                                let rows = rows
+                                    .into_iter()
+                                    .filter_map(|(label, value)| value.filter(|v| !v.trim().is_empty()).map(|v| (label, v)))
+                                    .map(|(label, value)| view! { cx, <tr><th>{label}</th><td>{value}</td></tr> })
+                                    .collect::<Vec<_>>();
+                                let proposal_messages = proposal.messages.clone().unwrap_or_default();
+                                let final_tally = proposal.final_tally_result.clone();
+                                let is_voting = proposal.status == ProposalStatus::VotingPeriod;
+                                let final_status = proposal.status.clone();
+                                let expedited = proposal.expedited.unwrap_or(false);
+                                let share_url = proposal_share_url(&chain.chain_id, proposal_id);
+                                let tally_chain = chain.clone();
+                                let simulator_chain = chain.clone();
+                                let votes_chain = chain.clone();
+                                view! { cx,
+                                    <div>
+                                        <h2>
+                                            <span class="proposal-number">{format!("Proposal #{}: ", proposal_id)}</span>
+                                            <span class="proposal-title">{title}</span>
+                                        </h2>
+                                        <hr class="gold-line" />
+                                        <div class="share-link">
+                                            <span>"Share: "</span>
+                                            <input class="watch-input" readonly=true prop:value=share_url />
+                                        </div>
+                                        <table class="message-fields proposal-fields">{rows}</table>
+                                        {description.map(|desc| view! { cx, <Markdown source=desc /> })}
+                                        {metadata_link.map(|url| view! { cx,
+                                            <p class="share-link">
+                                                <a href=url target="_blank" rel="noopener noreferrer nofollow">"Open the proposal's metadata"</a>
+                                                <span>"(hosted by the proposer, so not loaded automatically)"</span>
+                                            </p>
+                                        })}
+                                        {is_ipfs.then(|| view! { cx,
+                                            <div class="share-link">
+                                                <span>"IPFS gateway: "</span>
+                                                <input
+                                                    class="watch-input"
+                                                    prop:value=ipfs_gateway
+                                                    on:change=move |ev| {
+                                                        let gateway = event_target_value(&ev);
+                                                        let gateway = if gateway.trim().is_empty() { metadata::DEFAULT_IPFS_GATEWAY.to_string() } else { gateway };
+                                                        metadata::save_gateway(&gateway);
+                                                        set_ipfs_gateway.set(gateway);
+                                                    }
+                                                />
+                                            </div>
+                                        })}
+                                        {move || match proposal_metadata.get() {
+                                            None => view! { cx, <span></span> }.into_view(cx),
+                                            Some(Err(err)) => view! { cx,
+                                                <p class="watch-error">{format!("Could not load the proposal metadata: {}", err)}</p>
+                                            }.into_view(cx),
+                                            Some(Ok(meta)) => render_metadata(cx, meta, &summary),
+                                        }}
+                                        {move || {
+                                            if is_voting {
+                                                live_tallies.get().get(&proposal_id).map(|tally| {
+                                                    render_tally(cx, tally, tally_context.get().as_ref(), TallyKind::Live, expedited, &tally_chain)
+                                                })
+                                            } else if final_status.has_ended() {
+                                                final_tally.as_ref().map(|tally| {
+                                                    render_tally(cx, tally, None, TallyKind::Final(final_status.clone()), false, &tally_chain)
+                                                })
+                                            } else {
+                                                None
+                                            }
+                                        }}
+                                        {is_voting.then(|| view! { cx,
+                                            <WhatIfSimulator
+                                                proposal_id=proposal_id
+                                                chain=simulator_chain
+                                                expedited=expedited
+                                                tally=Signal::derive(cx, move || live_tallies.get().get(&proposal_id).cloned())
+                                                context=Signal::derive(cx, move || tally_context.get())
+                                            />
+                                        })}
+                                        {is_voting.then(|| view! { cx,
+                                            <ValidatorVotes
+                                                proposal_id=proposal_id
+                                                chain=votes_chain
+                                                delegations=Signal::derive(cx, move || gov_delegations.get())
+                                            />
+                                        })}
+                                        <h3>"Messages"</h3>
+                                        {move || {
+                                            let timing = block_timing.get();
+                                            let context = DecodeContext { chain: &chain, block_timing: timing.as_ref(), time_zone: time_zone.get() };
+                                            proposal_messages
+                                                .iter()
+                                                .map(|msg| view! { cx, <MessageCard message=messages::decode(msg, &context) lcd=chain.lcd.clone() /> }.into_view(cx))
+                                                .collect::<Vec<_>>()
+                                        }}
+                                    </div>
+                                }.into_view(cx)
+                            }
+                        }}
+                    </div>
+                },
                 "API" => view! { cx,
                     <div class="api-section">
                         <h2>"API Endpoint Info : "</h2>
@@ -1516,6 +3289,52 @@ pub fn App(cx: Scope) -> impl IntoView {
                                 <p>{move || result.get()}</p>
                             </div>
                         </div>
+
+                        <h2 class="tools-heading">"Address Converter :"</h2>
+                        <hr class="gold-line" />
+                        <h3>"Validate a bech32 address and convert it between chain prefixes."</h3>
+                        <div class="calculator">
+                            <div class="input-row">
+                                <label for="converter-address">"Address:"</label>
+                                <input
+                                    id="converter-address"
+                                    type="text"
+                                    placeholder="secret1..., cosmos1..., osmovaloper1..."
+                                    prop:value=move || converter_input.get()
+                                    on:input=move |ev| set_converter_input.set(event_target_value(&ev))
+                                />
+                            </div>
+                            {move || {
+                                let input = converter_input.get();
+                                let input = input.trim();
+                                if input.is_empty() {
+                                    return view! { cx, <p>"Enter an address to convert."</p> }.into_view(cx);
+                                }
+                                match bech32::decode(input) {
+                                    Err(err) => view! { cx,
+                                        <p class="watch-error">{format!("❌ Invalid address: {}", err)}</p>
+                                    }.into_view(cx),
+                                    Ok(decoded) => view! { cx,
+                                        <div class="converter-result">
+                                            <p>{format!("✅ Valid checksum · prefix \"{}\" · {} bytes", decoded.prefix, decoded.bytes.len())}</p>
+                                            <p class="account-line">{format!("Hex : {}", bech32::to_hex(&decoded.bytes))}</p>
+                                            <table class="converter-table">
+                                                {chains::known_prefixes().into_iter().map(|prefix| {
+                                                    let converted = bech32::encode(&prefix, &decoded.bytes)
+                                                        .unwrap_or_else(|e| e.to_string());
+                                                    view! { cx,
+                                                        <tr>
+                                                            <td class="converter-prefix">{prefix}</td>
+                                                            <td class="account-line">{converted}</td>
+                                                        </tr>
+                                                    }
+                                                }).collect::<Vec<_>>()}
+                                            </table>
+                                        </div>
+                                    }.into_view(cx),
+                                }
+                            }}
+                        </div>
                     </div>
                 },                                                                                               
                 _ => view! { cx,
@@ -1524,6 +3343,30 @@ pub fn App(cx: Scope) -> impl IntoView {
                     </div>
                 },
             }}
+
+            // Wallet picker
+            {move || show_connect_dialog.get().then(|| view! { cx,
+                <div class="modal">
+                    <div class="modal-content wallet-picker">
+                        <p>"Choose a wallet to connect :"</p>
+                        {WalletKind::ALL.iter().map(|kind| {
+                            let kind = *kind;
+                            if kind.is_installed() {
+                                view! { cx,
+                                    <button class="wallet-option" on:click=move |_| connect_with(kind)>{kind.name()}</button>
+                                }.into_view(cx)
+                            } else {
+                                view! { cx,
+                                    <a class="wallet-option not-installed" href={kind.install_url()} target="_blank">
+                                        {format!("{} (not installed)", kind.name())}
+                                    </a>
+                                }.into_view(cx)
+                            }
+                        }).collect::<Vec<_>>()}
+                        <button class="close-modal" on:click=move |_| set_show_connect_dialog.set(false)>"Close"</button>
+                    </div>
+                </div>
+            })}
         </div>
     }
 }
@@ -0,0 +1,535 @@
+use leptos::*;
+use std::collections::HashMap;
+
+// Minimal Markdown renderer for proposal descriptions.
+// Parsing produces a small block/inline tree that is rendered as Leptos views, never as
+// innerHTML, so raw HTML in a description shows up as text. Links only keep allow-listed schemes.
+
+// Descriptions longer than this collapse behind "Read more"
+const COLLAPSE_CHARS: usize = 800;
+const COLLAPSE_BLOCKS: usize = 6;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    Text(String),
+    Code(String),
+    Strong(Vec<Inline>),
+    Emphasis(Vec<Inline>),
+    Link { text: Vec<Inline>, href: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading(usize, Vec<Inline>),
+    Paragraph(Vec<Inline>),
+    List { ordered: bool, items: Vec<Vec<Inline>> },
+    Code(String),
+    Quote(Vec<Inline>),
+    Table { header: Vec<Vec<Inline>>, rows: Vec<Vec<Vec<Inline>>> },
+    Rule,
+}
+
+// Only web and mail links survive; javascript:, data: and anything else become plain text
+pub fn safe_url(href: &str) -> Option<String> {
+    let href = href.trim();
+    let lower = href.to_ascii_lowercase();
+    let allowed = ["https://", "http://", "mailto:"].iter().any(|scheme| lower.starts_with(scheme));
+    (allowed && !href.chars().any(|c| c.is_whitespace() || c.is_control())).then(|| href.to_string())
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|&c| c == '#').count();
+    let rest = &trimmed[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')))
+        .then(|| (level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_'].iter().any(|&marker| compact.chars().all(|c| c == marker))
+}
+
+fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+// "- item", "* item", "+ item" or "1. item" / "1) item"; returns (ordered, item text)
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    let trimmed = line.trim_start();
+    if let Some(rest) = ["- ", "* ", "+ "].iter().find_map(|marker| trimmed.strip_prefix(marker)) {
+        return Some((false, rest));
+    }
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 || digits > 9 {
+        return None;
+    }
+    let rest = &trimmed[digits..];
+    rest.strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))
+        .map(|item| (true, item))
+}
+
+fn table_cells(line: &str) -> Vec<&str> {
+    let trimmed = line.trim();
+    let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let trimmed = trimmed.strip_suffix('|').unwrap_or(trimmed);
+    trimmed.split('|').map(str::trim).collect()
+}
+
+fn is_table_separator(line: &str) -> bool {
+    line.contains('-')
+        && table_cells(line).iter().all(|cell| {
+            let cell = cell.trim_matches(':');
+            !cell.is_empty() && cell.chars().all(|c| c == '-')
+        })
+}
+
+fn starts_block(line: &str) -> bool {
+    heading(line).is_some()
+        || is_fence(line)
+        || is_rule(line)
+        || list_item(line).is_some()
+        || line.trim_start().starts_with('>')
+}
+
+pub fn parse(source: &str) -> Vec<Block> {
+    // On-chain descriptions often carry escaped newlines instead of real ones
+    let source = source.replace("\\n", "\n").replace("\r\n", "\n");
+    let lines: Vec<&str> = source.lines().collect();
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        if line.trim().is_empty() {
+            i += 1;
+        } else if is_fence(line) {
+            let fence = &line.trim_start()[..3];
+            let mut code = Vec::new();
+            i += 1;
+            while i < lines.len() && !lines[i].trim_start().starts_with(fence) {
+                code.push(lines[i]);
+                i += 1;
+            }
+            i += 1;
+            blocks.push(Block::Code(code.join("\n")));
+        } else if let Some((level, text)) = heading(line) {
+            blocks.push(Block::Heading(level, parse_inline(text)));
+            i += 1;
+        } else if is_rule(line) {
+            blocks.push(Block::Rule);
+            i += 1;
+        } else if line.contains('|') && lines.get(i + 1).is_some_and(|next| is_table_separator(next)) {
+            let header = table_cells(line).into_iter().map(parse_inline).collect();
+            let mut rows = Vec::new();
+            i += 2;
+            while i < lines.len() && lines[i].contains('|') && !lines[i].trim().is_empty() {
+                rows.push(table_cells(lines[i]).into_iter().map(parse_inline).collect());
+                i += 1;
+            }
+            blocks.push(Block::Table { header, rows });
+        } else if let Some((ordered, _)) = list_item(line) {
+            let mut items: Vec<String> = Vec::new();
+            while i < lines.len() {
+                match list_item(lines[i]) {
+                    Some((item_ordered, text)) if item_ordered == ordered => items.push(text.trim().to_string()),
+                    Some(_) => break,
+                    // Indented continuation lines belong to the previous item
+                    None if lines[i].starts_with(' ') && !lines[i].trim().is_empty() => {
+                        if let Some(last) = items.last_mut() {
+                            last.push(' ');
+                            last.push_str(lines[i].trim());
+                        }
+                    }
+                    None => break,
+                }
+                i += 1;
+            }
+            let items = items.iter().map(|item| parse_inline(item)).collect();
+            blocks.push(Block::List { ordered, items });
+        } else if line.trim_start().starts_with('>') {
+            let mut quote = Vec::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                quote.push(lines[i].trim_start()[1..].trim());
+                i += 1;
+            }
+            blocks.push(Block::Quote(parse_inline(&quote.join(" "))));
+        } else {
+            let mut paragraph = vec![line.trim()];
+            i += 1;
+            while i < lines.len() && !lines[i].trim().is_empty() && !starts_block(lines[i]) {
+                paragraph.push(lines[i].trim());
+                i += 1;
+            }
+            blocks.push(Block::Paragraph(parse_inline(&paragraph.join(" "))));
+        }
+    }
+    blocks
+}
+
+// Finds closing delimiters, remembering where each pattern next occurs so a run of unmatched
+// `[`, `*` or `` ` `` doesn't rescan the rest of the text for every one of them
+struct Delimiters<'a> {
+    chars: &'a [char],
+    // pattern -> (position searched from, first match at or after it)
+    next: HashMap<&'static str, (usize, Option<usize>)>,
+}
+
+impl<'a> Delimiters<'a> {
+    fn new(chars: &'a [char]) -> Self {
+        Delimiters { chars, next: HashMap::new() }
+    }
+
+    fn find(&mut self, from: usize, pattern: &'static str) -> Option<usize> {
+        if let Some(&(searched_from, found)) = self.next.get(pattern) {
+            if from >= searched_from && found.is_none_or(|at| at >= from) {
+                return found;
+            }
+        }
+        let needle: Vec<char> = pattern.chars().collect();
+        let found = (from..self.chars.len()).find(|&i| self.chars[i..].starts_with(&needle));
+        self.next.insert(pattern, (from, found));
+        found
+    }
+}
+
+fn flush(text: &mut String, out: &mut Vec<Inline>) {
+    if !text.is_empty() {
+        out.push(Inline::Text(std::mem::take(text)));
+    }
+}
+
+pub fn parse_inline(source: &str) -> Vec<Inline> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut delimiters = Delimiters::new(&chars);
+    let mut out = Vec::new();
+    let mut text = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let rest: String = chars[i..chars.len().min(i + 8)].iter().collect();
+        let after_word = i > 0 && chars[i - 1].is_alphanumeric();
+
+        if c == '\\' && chars.get(i + 1).is_some_and(|n| n.is_ascii_punctuation()) {
+            text.push(chars[i + 1]);
+            i += 2;
+        } else if c == '`' {
+            if let Some(end) = delimiters.find(i + 1, "`") {
+                flush(&mut text, &mut out);
+                out.push(Inline::Code(chars[i + 1..end].iter().collect()));
+                i = end + 1;
+            } else {
+                text.push(c);
+                i += 1;
+            }
+        } else if rest.starts_with("**") || rest.starts_with("__") {
+            let marker = if c == '*' { "**" } else { "__" };
+            match delimiters.find(i + 2, marker).filter(|&end| end > i + 2) {
+                Some(end) => {
+                    flush(&mut text, &mut out);
+                    out.push(Inline::Strong(parse_inline(&chars[i + 2..end].iter().collect::<String>())));
+                    i = end + 2;
+                }
+                None => {
+                    text.push_str(marker);
+                    i += 2;
+                }
+            }
+        } else if (c == '*' || (c == '_' && !after_word)) && chars.get(i + 1).is_some_and(|n| !n.is_whitespace()) {
+            let closing = delimiters
+                .find(i + 1, if c == '*' { "*" } else { "_" })
+                .filter(|&end| end > i + 1 && !(c == '_' && chars.get(end + 1).is_some_and(|n| n.is_alphanumeric())));
+            match closing {
+                Some(end) => {
+                    flush(&mut text, &mut out);
+                    out.push(Inline::Emphasis(parse_inline(&chars[i + 1..end].iter().collect::<String>())));
+                    i = end + 1;
+                }
+                None => {
+                    text.push(c);
+                    i += 1;
+                }
+            }
+        } else if c == '[' {
+            // [text](url)
+            let link = delimiters.find(i + 1, "](").and_then(|mid| delimiters.find(mid + 2, ")").map(|end| (mid, end)));
+            match link {
+                Some((mid, end)) => {
+                    flush(&mut text, &mut out);
+                    let label = parse_inline(&chars[i + 1..mid].iter().collect::<String>());
+                    let href: String = chars[mid + 2..end].iter().collect();
+                    match safe_url(&href) {
+                        Some(href) => out.push(Inline::Link { text: label, href }),
+                        None => out.extend(label),
+                    }
+                    i = end + 1;
+                }
+                None => {
+                    text.push(c);
+                    i += 1;
+                }
+            }
+        } else if c == '<' && (rest.starts_with("<http") || rest.starts_with("<mailto")) {
+            // <https://autolink>
+            match delimiters.find(i + 1, ">") {
+                Some(end) => {
+                    let href: String = chars[i + 1..end].iter().collect();
+                    match safe_url(&href) {
+                        Some(href) => {
+                            flush(&mut text, &mut out);
+                            out.push(Inline::Link { text: vec![Inline::Text(href.clone())], href });
+                        }
+                        None => text.push_str(&format!("<{}>", href)),
+                    }
+                    i = end + 1;
+                }
+                None => {
+                    text.push(c);
+                    i += 1;
+                }
+            }
+        } else if !after_word && (rest.starts_with("https://") || rest.starts_with("http://")) {
+            // Bare URLs, minus trailing punctuation that belongs to the sentence
+            let end = (i..chars.len()).find(|&j| chars[j].is_whitespace()).unwrap_or(chars.len());
+            let mut url: String = chars[i..end].iter().collect();
+            while url.ends_with(['.', ',', ';', ':', ')', '!', '?']) {
+                url.pop();
+            }
+            let consumed = url.chars().count();
+            flush(&mut text, &mut out);
+            out.push(Inline::Link { text: vec![Inline::Text(url.clone())], href: url });
+            i += consumed;
+        } else {
+            text.push(c);
+            i += 1;
+        }
+    }
+    flush(&mut text, &mut out);
+    out
+}
+
+fn render_inlines(cx: Scope, inlines: &[Inline]) -> Vec<View> {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.clone().into_view(cx),
+            Inline::Code(code) => view! { cx, <code>{code.clone()}</code> }.into_view(cx),
+            Inline::Strong(children) => view! { cx, <strong>{render_inlines(cx, children)}</strong> }.into_view(cx),
+            Inline::Emphasis(children) => view! { cx, <em>{render_inlines(cx, children)}</em> }.into_view(cx),
+            Inline::Link { text, href } => view! { cx,
+                <a href={href.clone()} target="_blank" rel="noopener noreferrer">{render_inlines(cx, text)}</a>
+            }
+            .into_view(cx),
+        })
+        .collect()
+}
+
+fn render_block(cx: Scope, block: &Block) -> View {
+    match block {
+        // Proposal titles are h3, so description headings start below them
+        Block::Heading(1, text) => view! { cx, <h4>{render_inlines(cx, text)}</h4> }.into_view(cx),
+        Block::Heading(2, text) => view! { cx, <h5>{render_inlines(cx, text)}</h5> }.into_view(cx),
+        Block::Heading(_, text) => view! { cx, <h6>{render_inlines(cx, text)}</h6> }.into_view(cx),
+        Block::Paragraph(text) => view! { cx, <p>{render_inlines(cx, text)}</p> }.into_view(cx),
+        Block::List { ordered, items } => {
+            let items = items
+                .iter()
+                .map(|item| view! { cx, <li>{render_inlines(cx, item)}</li> })
+                .collect::<Vec<_>>();
+            if *ordered {
+                view! { cx, <ol>{items}</ol> }.into_view(cx)
+            } else {
+                view! { cx, <ul>{items}</ul> }.into_view(cx)
+            }
+        }
+        Block::Code(code) => view! { cx, <pre class="md-code"><code>{code.clone()}</code></pre> }.into_view(cx),
+        Block::Quote(text) => view! { cx, <blockquote>{render_inlines(cx, text)}</blockquote> }.into_view(cx),
+        Block::Table { header, rows } => view! { cx,
+            <table class="md-table">
+                <thead>
+                    <tr>{header.iter().map(|cell| view! { cx, <th>{render_inlines(cx, cell)}</th> }).collect::<Vec<_>>()}</tr>
+                </thead>
+                <tbody>
+                    {rows.iter().map(|row| view! { cx,
+                        <tr>{row.iter().map(|cell| view! { cx, <td>{render_inlines(cx, cell)}</td> }).collect::<Vec<_>>()}</tr>
+                    }).collect::<Vec<_>>()}
+                </tbody>
+            </table>
+        }
+        .into_view(cx),
+        Block::Rule => view! { cx, <hr /> }.into_view(cx),
+    }
+}
+
+// Rendered Markdown that collapses behind "Read more" when it is long
+#[component]
+pub fn Markdown(cx: Scope, source: String) -> impl IntoView {
+    let blocks = parse(&source);
+    let collapsible = source.chars().count() > COLLAPSE_CHARS || blocks.len() > COLLAPSE_BLOCKS;
+    let (expanded, set_expanded) = create_signal(cx, false);
+    let rendered = blocks.iter().map(|block| render_block(cx, block)).collect::<Vec<_>>();
+
+    view! { cx,
+        <div class="markdown-body">
+            <div class=move || if collapsible && !expanded.get() { "markdown collapsed" } else { "markdown" }>
+                {rendered}
+            </div>
+            {collapsible.then(|| view! { cx,
+                <button class="read-more" on:click=move |_| set_expanded.update(|e| *e = !*e)>
+                    {move || if expanded.get() { "Show less" } else { "Read more" }}
+                </button>
+            })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(value: &str) -> Inline {
+        Inline::Text(value.to_string())
+    }
+
+    fn link(label: &str, href: &str) -> Inline {
+        Inline::Link { text: vec![text(label)], href: href.to_string() }
+    }
+
+    #[test]
+    fn allows_only_web_and_mail_urls() {
+        assert_eq!(safe_url("https://forum.example/t/1"), Some("https://forum.example/t/1".to_string()));
+        assert_eq!(safe_url(" HTTP://example.com "), Some("HTTP://example.com".to_string()));
+        assert_eq!(safe_url("mailto:gov@example.com"), Some("mailto:gov@example.com".to_string()));
+        for href in [
+            "javascript:alert(1)",
+            " JaVaScRiPt:alert(1)",
+            "data:text/html;base64,PHNjcmlwdD4=",
+            "vbscript:msgbox",
+            "//evil.example",
+            "ftp://example.com",
+            "https://exa mple.com",
+            "https://example.com/\tx",
+            "https://example.com/\u{0}x",
+            "https://example.com/\u{7f}",
+        ] {
+            assert_eq!(safe_url(href), None, "{:?} should be rejected", href);
+        }
+    }
+
+    #[test]
+    fn keeps_raw_html_as_text() {
+        assert_eq!(
+            parse("<script>alert(1)</script>"),
+            vec![Block::Paragraph(vec![text("<script>alert(1)</script>")])]
+        );
+        assert_eq!(parse_inline("<img src=x onerror=alert(1)>"), vec![text("<img src=x onerror=alert(1)>")]);
+    }
+
+    #[test]
+    fn links_fall_back_to_their_label_for_rejected_urls() {
+        assert_eq!(parse_inline("[forum](https://forum.example)"), vec![link("forum", "https://forum.example")]);
+        assert_eq!(parse_inline("[click](javascript:alert(1))"), vec![text("click"), text(")")]);
+        assert_eq!(parse_inline("[**bold**](data:text/html,x)"), vec![Inline::Strong(vec![text("bold")])]);
+    }
+
+    #[test]
+    fn links_bare_urls_and_autolinks() {
+        assert_eq!(
+            parse_inline("See https://forum.example/t/1. Thanks"),
+            vec![text("See "), link("https://forum.example/t/1", "https://forum.example/t/1"), text(". Thanks")]
+        );
+        assert_eq!(parse_inline("<https://example.com>"), vec![link("https://example.com", "https://example.com")]);
+        assert_eq!(parse_inline("<mailto:gov@example.com>"), vec![link("mailto:gov@example.com", "mailto:gov@example.com")]);
+        assert_eq!(parse_inline("<http://exa mple.com>"), vec![text("<http://exa mple.com>")]);
+        assert_eq!(parse_inline("xhttps://example.com"), vec![text("xhttps://example.com")]);
+    }
+
+    #[test]
+    fn parses_inline_styles() {
+        assert_eq!(
+            parse_inline("**bold** and *em* and `co*de` and snake_case_name"),
+            vec![
+                Inline::Strong(vec![text("bold")]),
+                text(" and "),
+                Inline::Emphasis(vec![text("em")]),
+                text(" and "),
+                Inline::Code("co*de".to_string()),
+                text(" and snake_case_name"),
+            ]
+        );
+        assert_eq!(parse_inline(r"\*not em\*"), vec![text("*not em*")]);
+    }
+
+    #[test]
+    fn parses_headings() {
+        assert_eq!(
+            parse("# Title\n## Sub ##\n#NoSpace"),
+            vec![
+                Block::Heading(1, vec![text("Title")]),
+                Block::Heading(2, vec![text("Sub")]),
+                Block::Paragraph(vec![text("#NoSpace")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_lists() {
+        assert_eq!(
+            parse("- one\n* two\n  continued\n1. first\n2) second"),
+            vec![
+                Block::List { ordered: false, items: vec![vec![text("one")], vec![text("two continued")]] },
+                Block::List { ordered: true, items: vec![vec![text("first")], vec![text("second")]] },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_tables() {
+        assert_eq!(
+            parse("| Option | Share |\n|:--|--:|\n| Yes | 70% |\n| No | 30% |"),
+            vec![Block::Table {
+                header: vec![vec![text("Option")], vec![text("Share")]],
+                rows: vec![vec![vec![text("Yes")], vec![text("70%")]], vec![vec![text("No")], vec![text("30%")]]],
+            }]
+        );
+        assert_eq!(parse("a | b\nnot a separator"), vec![Block::Paragraph(vec![text("a | b not a separator")])]);
+    }
+
+    #[test]
+    fn keeps_fenced_code_verbatim() {
+        assert_eq!(
+            parse("```json\n{\"a\": 1}\n**not bold** <b>\n```\nafter"),
+            vec![Block::Code("{\"a\": 1}\n**not bold** <b>".to_string()), Block::Paragraph(vec![text("after")])]
+        );
+        assert_eq!(parse("~~~\nunclosed"), vec![Block::Code("unclosed".to_string())]);
+    }
+
+    #[test]
+    fn unescapes_newlines_from_chain_json() {
+        assert_eq!(
+            parse(r"# Upgrade\n\nBody line\n---\n> quoted"),
+            vec![
+                Block::Heading(1, vec![text("Upgrade")]),
+                Block::Paragraph(vec![text("Body line")]),
+                Block::Rule,
+                Block::Quote(vec![text("quoted")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_long_runs_of_unmatched_delimiters() {
+        for delimiter in ["[", "<http", "[a](", "_x"] {
+            let source = format!("x {}", delimiter.repeat(50_000));
+            assert_eq!(parse_inline(&source), vec![text(&source)]);
+        }
+        // Pairs of these do match, but finding each closing one must stay cheap
+        for delimiter in ["*", "`", "**a"] {
+            assert!(!parse_inline(&format!("x {}", delimiter.repeat(50_001))).is_empty());
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use leptos::*;
+use serde::Deserialize;
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+
+use crate::account::{self, Coin};
+use crate::chains::ChainConfig;
+use crate::params::ParamsDiff;
+use crate::time::{TimeZone, Timestamp};
+
+// Readable summaries of governance proposal messages, keyed by their protobuf @type
+
+// Latest block height and time plus the recent average block time, for upgrade height estimates
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct BlockTiming {
+    pub height: u64,
+    pub time_ms: f64,
+    pub seconds_per_block: f64,
+}
+
+// What decoders need besides the message itself
+pub struct DecodeContext<'a> {
+    pub chain: &'a ChainConfig,
+    pub block_timing: Option<&'a BlockTiming>,
+    pub time_zone: TimeZone,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedMessage {
+    pub type_url: String,
+    pub label: String,
+    pub fields: Vec<(String, String)>,
+    // Legacy proposals wrap their content, which is decoded as a message of its own
+    pub nested: Vec<DecodedMessage>,
+    pub raw: Value,
+}
+
+type Decoder = fn(&Value, &DecodeContext) -> Vec<(String, String)>;
+
+struct MessageDecoder {
+    type_url: &'static str,
+    label: &'static str,
+    decode: Decoder,
+}
+
+const REGISTRY: &[MessageDecoder] = &[
+    MessageDecoder { type_url: "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade", label: "Software Upgrade", decode: decode_software_upgrade },
+    MessageDecoder { type_url: "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal", label: "Software Upgrade", decode: decode_software_upgrade },
+    MessageDecoder { type_url: "/cosmos.upgrade.v1beta1.MsgCancelUpgrade", label: "Cancel Upgrade", decode: decode_authority },
+    MessageDecoder { type_url: "/cosmos.upgrade.v1beta1.CancelSoftwareUpgradeProposal", label: "Cancel Upgrade", decode: decode_text },
+    MessageDecoder { type_url: "/cosmos.distribution.v1beta1.MsgCommunityPoolSpend", label: "Community Pool Spend", decode: decode_community_pool_spend },
+    MessageDecoder { type_url: "/cosmos.distribution.v1beta1.CommunityPoolSpendProposal", label: "Community Pool Spend", decode: decode_community_pool_spend },
+    MessageDecoder { type_url: "/cosmos.gov.v1.MsgExecLegacyContent", label: "Legacy Content", decode: decode_authority },
+    MessageDecoder { type_url: "/cosmos.gov.v1beta1.TextProposal", label: "Text Proposal", decode: decode_text },
+    MessageDecoder { type_url: "/cosmos.params.v1beta1.ParameterChangeProposal", label: "Parameter Change", decode: decode_param_change },
+    MessageDecoder { type_url: "/ibc.core.client.v1.MsgRecoverClient", label: "Recover Client", decode: decode_client_recovery },
+    MessageDecoder { type_url: "/ibc.core.client.v1.ClientUpdateProposal", label: "IBC Client Update", decode: decode_client_recovery },
+    MessageDecoder { type_url: "/ibc.core.client.v1.MsgUpdateClient", label: "IBC Client Update", decode: decode_update_client },
+    MessageDecoder { type_url: "/ibc.core.client.v1.MsgIBCSoftwareUpgrade", label: "IBC Software Upgrade", decode: decode_software_upgrade },
+    MessageDecoder { type_url: "/ibc.core.client.v1.UpgradeProposal", label: "IBC Software Upgrade", decode: decode_software_upgrade },
+];
+
+// Every module's MsgUpdateParams shares one decoder
+const UPDATE_PARAMS_SUFFIX: &str = ".MsgUpdateParams";
+
+pub fn is_update_params(type_url: &str) -> bool {
+    type_url.ends_with(UPDATE_PARAMS_SUFFIX)
+}
+
+fn decoder_for(type_url: &str) -> Option<(&'static str, Decoder)> {
+    if is_update_params(type_url) {
+        return Some(("Update Parameters", decode_update_params as Decoder));
+    }
+    REGISTRY.iter().find(|d| d.type_url == type_url).map(|d| (d.label, d.decode))
+}
+
+// Friendly name for a message type, e.g. "/cosmos.upgrade.v1beta1.MsgSoftwareUpgrade" -> "Software Upgrade"
+pub fn message_label(type_url: &str) -> &'static str {
+    decoder_for(type_url).map(|(label, _)| label).unwrap_or("Unknown Proposal Type")
+}
+
+// Module that owns a message, e.g. "/cosmos.staking.v1beta1.MsgUpdateParams" -> "staking"
+pub fn module_name(type_url: &str) -> &str {
+    let parts: Vec<&str> = type_url.trim_start_matches('/').split('.').collect();
+    match parts.as_slice() {
+        [.., module, _version, _msg] => module,
+        _ => type_url,
+    }
+}
+
+fn text(value: &Value, key: &str) -> Option<String> {
+    match value.get(key)? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+fn push(fields: &mut Vec<(String, String)>, label: &str, value: Option<String>) {
+    if let Some(value) = value {
+        fields.push((label.to_string(), value));
+    }
+}
+
+// Compact one-line rendering for nested values
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "—".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn format_coins(value: Option<&Value>, chain: &ChainConfig) -> Option<String> {
+    let coins: Vec<Coin> = serde_json::from_value(value?.clone()).ok()?;
+    (!coins.is_empty()).then(|| {
+        coins
+            .iter()
+            .map(|coin| account::format_coin(coin, chain))
+            .collect::<Vec<_>>()
+            .join(", ")
+    })
+}
+
+fn decode_authority(value: &Value, _: &DecodeContext) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    push(&mut fields, "Authority", text(value, "authority"));
+    fields
+}
+
+fn decode_text(value: &Value, _: &DecodeContext) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    push(&mut fields, "Title", text(value, "title"));
+    fields
+}
+
+fn decode_software_upgrade(value: &Value, context: &DecodeContext) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    let plan = value.get("plan").unwrap_or(&Value::Null);
+    push(&mut fields, "Upgrade name", text(plan, "name"));
+    let height = text(plan, "height").and_then(|h| h.parse::<u64>().ok()).filter(|h| *h > 0);
+    push(&mut fields, "Upgrade height", height.map(|h| h.to_string()));
+
+    if let (Some(height), Some(timing)) = (height, context.block_timing) {
+        let estimate = if height > timing.height {
+            let seconds = (height - timing.height) as f64 * timing.seconds_per_block;
+            format!(
+                "{} (in ~{} blocks at {:.2}s/block)",
+                Timestamp::from_millis((timing.time_ms + seconds * 1000.0) as i64).format(context.time_zone),
+                height - timing.height,
+                timing.seconds_per_block
+            )
+        } else {
+            "Height already reached".to_string()
+        };
+        fields.push(("Estimated time".to_string(), estimate));
+    }
+
+    push(&mut fields, "Info", text(plan, "info"));
+    push(&mut fields, "Authority", text(value, "authority"));
+    if value.get("upgraded_client_state").is_some_and(|v| !v.is_null()) {
+        fields.push(("Upgraded client state".to_string(), "Included".to_string()));
+    }
+    fields
+}
+
+fn decode_community_pool_spend(value: &Value, context: &DecodeContext) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    push(&mut fields, "Recipient", text(value, "recipient"));
+    push(&mut fields, "Amount", format_coins(value.get("amount"), context.chain));
+    push(&mut fields, "Authority", text(value, "authority"));
+    fields
+}
+
+fn decode_param_change(value: &Value, _: &DecodeContext) -> Vec<(String, String)> {
+    value
+        .get("changes")
+        .and_then(|c| c.as_array())
+        .map(|changes| {
+            changes
+                .iter()
+                .map(|change| {
+                    let key = format!(
+                        "{}.{}",
+                        text(change, "subspace").unwrap_or_default(),
+                        text(change, "key").unwrap_or_default()
+                    );
+                    (key, text(change, "value").unwrap_or_default())
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn decode_update_params(value: &Value, _: &DecodeContext) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    let type_url = value.get("@type").and_then(|t| t.as_str()).unwrap_or_default();
+    fields.push(("Module".to_string(), module_name(type_url).to_string()));
+    push(&mut fields, "Authority", text(value, "authority"));
+    // The params themselves are shown as a diff against the live ones, see params::ParamsDiff
+    fields
+}
+
+fn decode_client_recovery(value: &Value, _: &DecodeContext) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    push(&mut fields, "Subject client", text(value, "subject_client_id"));
+    push(&mut fields, "Substitute client", text(value, "substitute_client_id"));
+    push(&mut fields, "Signer", text(value, "signer"));
+    fields
+}
+
+fn decode_update_client(value: &Value, _: &DecodeContext) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    push(&mut fields, "Client", text(value, "client_id"));
+    push(&mut fields, "Signer", text(value, "signer"));
+    fields
+}
+
+// Unknown types still show their scalar top-level fields
+fn decode_unknown(value: &Value) -> Vec<(String, String)> {
+    value
+        .as_object()
+        .map(|object| {
+            object
+                .iter()
+                .filter(|(key, value)| key.as_str() != "@type" && !value.is_object() && !value.is_array())
+                .map(|(key, value)| (key.clone(), display_value(value)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn decode(message: &Value, context: &DecodeContext) -> DecodedMessage {
+    let type_url = message.get("@type").and_then(|t| t.as_str()).unwrap_or("Unknown Type").to_string();
+    let (label, fields) = match decoder_for(&type_url) {
+        Some((label, decode)) => (label.to_string(), decode(message, context)),
+        None => (type_url.rsplit('.').next().unwrap_or(&type_url).to_string(), decode_unknown(message)),
+    };
+    let nested = message
+        .get("content")
+        .filter(|content| content.get("@type").is_some())
+        .map(|content| vec![decode(content, context)])
+        .unwrap_or_default();
+    DecodedMessage { type_url, label, fields, nested, raw: message.clone() }
+}
+
+pub async fn fetch_block_timing(chain: &ChainConfig) -> Result<BlockTiming, String> {
+    crate::call_js_async("fetchBlockTiming", &[JsValue::from_str(&chain.lcd)]).await
+}
+
+fn render_fields(cx: Scope, message: &DecodedMessage) -> View {
+    view! { cx,
+        <div class="message-summary">
+            <table class="message-fields">
+                {message.fields.iter().map(|(label, value)| view! { cx,
+                    <tr>
+                        <th>{label.clone()}</th>
+                        <td>{value.clone()}</td>
+                    </tr>
+                }).collect::<Vec<_>>()}
+            </table>
+            {message.nested.iter().map(|nested| view! { cx,
+                <div class="message-nested">
+                    <p class="message-label">{nested.label.clone()}</p>
+                    {render_fields(cx, nested)}
+                </div>
+            }).collect::<Vec<_>>()}
+        </div>
+    }
+    .into_view(cx)
+}
+
+// Summary card for one proposal message, raw JSON behind a toggle.
+// `lcd` is the chain's, for lookups such as the current params a MsgUpdateParams replaces.
+#[component]
+pub fn MessageCard(cx: Scope, message: DecodedMessage, lcd: String) -> impl IntoView {
+    let (show_raw, set_show_raw) = create_signal(cx, false);
+    let raw = serde_json::to_string_pretty(&message.raw).unwrap_or_else(|_| "Invalid JSON".to_string());
+    let params_diff = message
+        .raw
+        .get("params")
+        .filter(|_| is_update_params(&message.type_url))
+        .map(|proposed| view! { cx, <ParamsDiff lcd=lcd type_url=message.type_url.clone() proposed=proposed.clone() /> });
+
+    view! { cx,
+        <div class="message-card">
+            <div class="message-header">
+                <span class="message-label">{message.label.clone()}</span>
+                <span class="message-type">{message.type_url.clone()}</span>
+            </div>
+            {render_fields(cx, &message)}
+            {params_diff}
+            <button class="read-more" on:click=move |_| set_show_raw.update(|s| *s = !*s)>
+                {move || if show_raw.get() { "Hide raw JSON" } else { "Show raw JSON" }}
+            </button>
+            {move || show_raw.get().then(|| view! { cx, <pre class="formatted-json">{raw.clone()}</pre> })}
+        </div>
+    }
+}
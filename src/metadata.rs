@@ -0,0 +1,192 @@
+use serde::{Deserialize, Deserializer};
+use wasm_bindgen::JsValue;
+
+use crate::storage;
+
+// Gov v1 proposal metadata: inline JSON, or a pointer to a JSON document on IPFS
+
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+
+// localStorage key of the gateway picked on the proposal view, e.g. a local node's
+// http://127.0.0.1:8080/ipfs/
+const GATEWAY_STORAGE_KEY: &str = "yolonode.ipfs_gateway";
+
+// The schema recommended by the x/gov spec; every field is optional in practice
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ProposalMetadata {
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_authors")]
+    pub authors: Vec<String>,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub details: Option<String>,
+    #[serde(default)]
+    pub proposal_forum_url: Option<String>,
+    #[serde(default)]
+    pub vote_option_context: Option<String>,
+}
+
+// `authors` is a list per the spec, but plenty of proposals use a single string
+fn deserialize_authors<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::String(author) => vec![author],
+        serde_json::Value::Array(authors) => authors.into_iter().filter_map(|a| a.as_str().map(str::to_string)).collect(),
+        _ => Vec::new(),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataSource {
+    Empty,
+    Inline(ProposalMetadata),
+    // CID plus optional path, e.g. "bafy.../metadata.json"
+    Ipfs(String),
+    Url(String),
+    Text(String),
+}
+
+// CIDv0 (base58 "Qm...", 46 chars) or CIDv1 in the usual base32 form ("b...")
+fn is_cid(value: &str) -> bool {
+    let cid = value.split('/').next().unwrap_or_default();
+    let base58 = cid.len() == 46 && cid.starts_with("Qm") && cid.chars().all(|c| c.is_ascii_alphanumeric() && !"0OIl".contains(c));
+    let base32 = cid.len() >= 50 && cid.starts_with('b') && cid.chars().all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c));
+    base58 || base32
+}
+
+pub fn parse(metadata: &str) -> MetadataSource {
+    let metadata = metadata.trim();
+    if metadata.is_empty() {
+        return MetadataSource::Empty;
+    }
+    if metadata.starts_with('{') {
+        if let Ok(parsed) = serde_json::from_str(metadata) {
+            return MetadataSource::Inline(parsed);
+        }
+    }
+    if let Some(path) = metadata.strip_prefix("ipfs://").map(|p| p.trim_start_matches("ipfs/")) {
+        return MetadataSource::Ipfs(path.to_string());
+    }
+    if is_cid(metadata) {
+        return MetadataSource::Ipfs(metadata.to_string());
+    }
+    if metadata.starts_with("https://") || metadata.starts_with("http://") {
+        return MetadataSource::Url(metadata.to_string());
+    }
+    MetadataSource::Text(metadata.to_string())
+}
+
+pub fn load_gateway() -> String {
+    storage::load(GATEWAY_STORAGE_KEY).unwrap_or_else(|| DEFAULT_IPFS_GATEWAY.to_string())
+}
+
+pub fn save_gateway(gateway: &str) {
+    storage::save(GATEWAY_STORAGE_KEY, gateway);
+}
+
+// e.g. ("https://ipfs.io/ipfs", "bafy...") -> "https://ipfs.io/ipfs/bafy..."
+pub fn gateway_url(gateway: &str, path: &str) -> String {
+    format!("{}/{}", gateway.trim().trim_end_matches('/'), path)
+}
+
+// Where the metadata document can be fetched automatically: only through the user's gateway.
+// A proposer-chosen URL would reveal every viewer's IP to whoever hosts it, so it is only linked.
+pub fn document_url(source: &MetadataSource, gateway: &str) -> Option<String> {
+    match source {
+        MetadataSource::Ipfs(path) => Some(gateway_url(gateway, path)),
+        _ => None,
+    }
+}
+
+pub async fn fetch_metadata(url: &str) -> Result<ProposalMetadata, String> {
+    crate::call_js_async("fetchProposalMetadata", &[JsValue::from_str(url)]).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CID_V0: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+    const CID_V1: &str = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+    #[test]
+    fn parses_inline_json() {
+        let source = parse(r#" {"title": "Upgrade", "authors": ["alice", "bob"], "details": "More"} "#);
+        let MetadataSource::Inline(meta) = source else {
+            panic!("expected inline metadata, got {:?}", source);
+        };
+        assert_eq!(meta.title.as_deref(), Some("Upgrade"));
+        assert_eq!(meta.authors, vec!["alice", "bob"]);
+        assert_eq!(meta.details.as_deref(), Some("More"));
+        assert_eq!(meta.summary, None);
+    }
+
+    #[test]
+    fn falls_back_to_text_for_invalid_json() {
+        assert_eq!(parse("{not json"), MetadataSource::Text("{not json".to_string()));
+    }
+
+    #[test]
+    fn parses_ipfs_references() {
+        assert_eq!(parse(&format!("ipfs://{}", CID_V1)), MetadataSource::Ipfs(CID_V1.to_string()));
+        assert_eq!(
+            parse(&format!("ipfs://ipfs/{}/metadata.json", CID_V0)),
+            MetadataSource::Ipfs(format!("{}/metadata.json", CID_V0))
+        );
+        assert_eq!(parse(CID_V0), MetadataSource::Ipfs(CID_V0.to_string()));
+        assert_eq!(parse(CID_V1), MetadataSource::Ipfs(CID_V1.to_string()));
+    }
+
+    #[test]
+    fn parses_urls_text_and_empty() {
+        assert_eq!(parse("https://forum.example/t/1"), MetadataSource::Url("https://forum.example/t/1".to_string()));
+        assert_eq!(parse("http://example.com/m.json"), MetadataSource::Url("http://example.com/m.json".to_string()));
+        assert_eq!(parse("see the forum"), MetadataSource::Text("see the forum".to_string()));
+        assert_eq!(parse("   "), MetadataSource::Empty);
+    }
+
+    #[test]
+    fn recognizes_cids() {
+        assert!(is_cid(CID_V0));
+        assert!(is_cid(CID_V1));
+        assert!(is_cid(&format!("{}/metadata.json", CID_V1)));
+        // "0" and "l" aren't in the base58 alphabet
+        assert!(!is_cid("Qm0wAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdl"));
+        assert!(!is_cid("QmTooShort"));
+        assert!(!is_cid("bafyUPPERCASEisnotbase32aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"));
+        assert!(!is_cid("hello world"));
+    }
+
+    #[test]
+    fn joins_gateway_and_path() {
+        assert_eq!(gateway_url("https://ipfs.io/ipfs/", CID_V0), format!("https://ipfs.io/ipfs/{}", CID_V0));
+        assert_eq!(gateway_url(" http://127.0.0.1:8080/ipfs ", "a/b.json"), "http://127.0.0.1:8080/ipfs/a/b.json");
+    }
+
+    #[test]
+    fn fetches_only_through_the_gateway() {
+        let gateway = "http://127.0.0.1:8080/ipfs/";
+        assert_eq!(
+            document_url(&MetadataSource::Ipfs(CID_V0.to_string()), gateway),
+            Some(format!("http://127.0.0.1:8080/ipfs/{}", CID_V0))
+        );
+        assert_eq!(document_url(&MetadataSource::Url("https://tracker.example/m.json".to_string()), gateway), None);
+        assert_eq!(document_url(&MetadataSource::Text("hi".to_string()), gateway), None);
+    }
+
+    #[test]
+    fn accepts_authors_as_string_or_list() {
+        let single: ProposalMetadata = serde_json::from_str(r#"{"authors": "alice"}"#).unwrap();
+        assert_eq!(single.authors, vec!["alice"]);
+        let list: ProposalMetadata = serde_json::from_str(r#"{"authors": ["alice", 7, "bob"]}"#).unwrap();
+        assert_eq!(list.authors, vec!["alice", "bob"]);
+        let other: ProposalMetadata = serde_json::from_str(r#"{"authors": null}"#).unwrap();
+        assert!(other.authors.is_empty());
+        let missing: ProposalMetadata = serde_json::from_str("{}").unwrap();
+        assert!(missing.authors.is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+use leptos::*;
+use serde_json::Value;
+use wasm_bindgen::JsValue;
+
+use crate::messages::{display_value, module_name};
+
+// What a MsgUpdateParams would change, compared field by field with the module's live params
+
+// Modules whose params query doesn't follow /{namespace}/{module}/{version}/params
+const PARAMS_PATHS: &[(&str, &str)] = &[
+    ("/cosmos.gov.v1.MsgUpdateParams", "/cosmos/gov/v1/params/tallying"),
+    ("/secret.compute.v1beta1.MsgUpdateParams", "/compute/v1beta1/params"),
+    ("/ibc.applications.transfer.v1.MsgUpdateParams", "/ibc/apps/transfer/v1/params"),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParamChange {
+    // Dotted path, e.g. "voting_params.voting_period"
+    pub key: String,
+    pub old: Option<Value>,
+    pub new: Option<Value>,
+}
+
+// LCD path of the params query for the module a MsgUpdateParams targets,
+// e.g. "/cosmos.staking.v1beta1.MsgUpdateParams" -> "/cosmos/staking/v1beta1/params"
+pub fn params_path(type_url: &str) -> Option<String> {
+    if let Some((_, path)) = PARAMS_PATHS.iter().find(|(t, _)| *t == type_url) {
+        return Some(path.to_string());
+    }
+    let package = type_url.strip_prefix('/')?.strip_suffix(".MsgUpdateParams")?;
+    (package.split('.').count() >= 3).then(|| format!("/{}/params", package.replace('.', "/")))
+}
+
+// Decimals are compared by value, so "0.500000000000000000" equals "0.5"
+fn normalize(value: &Value) -> Value {
+    match value {
+        Value::String(s) if s.contains('.') && s.parse::<f64>().is_ok() => {
+            Value::String(s.trim_end_matches('0').trim_end_matches('.').to_string())
+        }
+        Value::Number(n) => Value::String(n.to_string()),
+        other => other.clone(),
+    }
+}
+
+fn diff_into(key: &str, old: Option<&Value>, new: Option<&Value>, changes: &mut Vec<ParamChange>) {
+    match (old, new) {
+        (Some(Value::Object(old)), Some(Value::Object(new))) => {
+            let mut names: Vec<&String> = old.keys().chain(new.keys()).collect();
+            names.sort();
+            names.dedup();
+            for name in names {
+                let nested = if key.is_empty() { name.clone() } else { format!("{}.{}", key, name) };
+                diff_into(&nested, old.get(name), new.get(name), changes);
+            }
+        }
+        (old, new) if old.map(normalize) != new.map(normalize) => changes.push(ParamChange {
+            key: key.to_string(),
+            old: old.cloned(),
+            new: new.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+// Changed keys only. MsgUpdateParams replaces the whole params object, so a key the proposal
+// leaves out is reported as a change too.
+pub fn diff(current: &Value, proposed: &Value) -> Vec<ParamChange> {
+    let mut changes = Vec::new();
+    diff_into("", Some(current), Some(proposed), &mut changes);
+    changes
+}
+
+pub async fn fetch_current_params(lcd: &str, path: &str) -> Result<Value, String> {
+    crate::call_js_async("fetchModuleParams", &[JsValue::from_str(lcd), JsValue::from_str(path)]).await
+}
+
+fn describe(value: &Option<Value>) -> String {
+    value.as_ref().map(display_value).unwrap_or_else(|| "—".to_string())
+}
+
+// Old → new table for a MsgUpdateParams, fetched when the card is shown
+#[component]
+pub fn ParamsDiff(cx: Scope, lcd: String, type_url: String, proposed: Value) -> impl IntoView {
+    let (current, set_current) = create_signal(cx, None::<Result<Value, String>>);
+    let module = module_name(&type_url).to_string();
+    match params_path(&type_url) {
+        Some(path) => spawn_local(async move {
+            let params = fetch_current_params(&lcd, &path).await;
+            if let Err(err) = &params {
+                log::error!("❌ Error fetching {} params: {}", path, err);
+            }
+            set_current.set(Some(params));
+        }),
+        None => set_current.set(Some(Err(format!("no params query known for {}", type_url)))),
+    }
+
+    view! { cx,
+        <div class="params-diff">
+            {move || match current.get() {
+                None => view! { cx, <p>{format!("Loading current {} parameters...", module)}</p> }.into_view(cx),
+                Some(Err(err)) => {
+                    // Without the live values, show everything the proposal sets
+                    let fields = proposed.as_object().cloned().unwrap_or_default();
+                    view! { cx,
+                        <div>
+                            <p class="watch-error">{format!("Could not load the current parameters ({}), showing the proposed values.", err)}</p>
+                            <table class="message-fields">
+                                {fields.iter().map(|(key, value)| view! { cx,
+                                    <tr><th>{key.clone()}</th><td>{display_value(value)}</td></tr>
+                                }).collect::<Vec<_>>()}
+                            </table>
+                        </div>
+                    }.into_view(cx)
+                }
+                Some(Ok(current)) => {
+                    let changes = diff(&current, &proposed);
+                    if changes.is_empty() {
+                        return view! { cx,
+                            <p>"No differences from the current parameters (the proposal may already be applied)."</p>
+                        }.into_view(cx);
+                    }
+                    view! { cx,
+                        <table class="message-fields">
+                            <tr><th>"Parameter"</th><th>"Current"</th><th></th><th>"Proposed"</th></tr>
+                            {changes.iter().map(|change| view! { cx,
+                                <tr>
+                                    <th>{change.key.clone()}</th>
+                                    <td class="param-old">{describe(&change.old)}</td>
+                                    <td>"→"</td>
+                                    <td class="param-new">{describe(&change.new)}</td>
+                                </tr>
+                            }).collect::<Vec<_>>()}
+                        </table>
+                    }.into_view(cx)
+                }
+            }}
+        </div>
+    }
+}
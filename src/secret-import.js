@@ -1,8 +1,10 @@
-import { SecretNetworkClient } from 'secretjs';
+import { SecretNetworkClient, MsgCommunityPoolSpend } from 'secretjs';
 import { Buffer } from 'buffer';
 window.Buffer = Buffer;
 
 window.SecretNetworkClient = SecretNetworkClient;
+// Proposal messages window.submitProposal can sign
+window.MsgCommunityPoolSpend = MsgCommunityPoolSpend;
 
 // API URLs
 export const DEFAULT_LCD_ENDPOINT = "https://rpc.ankr.com/http/scrt_cosmos";
@@ -0,0 +1,72 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::status::ProposalStatus;
+use crate::storage;
+
+// localStorage key prefix holding what the Vote view looked like on the last visit, per chain
+const SEEN_STORAGE_KEY: &str = "yolonode.seen_proposals";
+
+fn storage_key(chain_id: &str) -> String {
+    format!("{}.{}", SEEN_STORAGE_KEY, chain_id)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct SeenProposals {
+    pub highest_id: u64,
+    // Last seen status of proposals that were still open; final ones can't change anymore
+    pub statuses: HashMap<u64, ProposalStatus>,
+}
+
+// What happened since the last visit
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProposalChanges {
+    pub new_ids: HashSet<u64>,
+    // Proposal id -> the status it had when last seen
+    pub changed: HashMap<u64, ProposalStatus>,
+}
+
+impl ProposalChanges {
+    pub fn count(&self) -> usize {
+        self.new_ids.len() + self.changed.len()
+    }
+}
+
+pub fn load_seen(chain_id: &str) -> Option<SeenProposals> {
+    storage::load(&storage_key(chain_id))
+}
+
+// Remember the current proposals (id, status) as seen
+pub fn mark_seen(chain_id: &str, proposals: &[(u64, &ProposalStatus)]) {
+    let seen = SeenProposals {
+        highest_id: proposals.iter().map(|(id, _)| *id).max().unwrap_or_default(),
+        statuses: proposals
+            .iter()
+            .filter(|(_, status)| status.is_active())
+            .map(|(id, status)| (*id, (*status).clone()))
+            .collect(),
+    };
+    storage::save(&storage_key(chain_id), &seen);
+}
+
+// Compare the current proposals against the last visit; a first visit reports nothing
+pub fn changes_since(seen: Option<&SeenProposals>, proposals: &[(u64, &ProposalStatus)]) -> ProposalChanges {
+    let Some(seen) = seen else {
+        return ProposalChanges::default();
+    };
+    ProposalChanges {
+        new_ids: proposals
+            .iter()
+            .filter(|(id, _)| *id > seen.highest_id)
+            .map(|(id, _)| *id)
+            .collect(),
+        changed: proposals
+            .iter()
+            .filter_map(|(id, status)| {
+                let previous = seen.statuses.get(id)?;
+                (previous != *status).then(|| (*id, previous.clone()))
+            })
+            .collect(),
+    }
+}
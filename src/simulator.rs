@@ -0,0 +1,243 @@
+use leptos::*;
+
+use crate::account;
+use crate::chains::ChainConfig;
+use crate::governance::{self, Validator, ValidatorVote, VoteOption};
+use crate::tally::{TallyContext, TallyResult};
+use crate::TallyKind;
+
+// "What-if" tallies: the live tally plus hypothetical votes, to see whether a proposal could
+// still be swung. Validators that vote bring their whole stake; delegators who voted themselves
+// would lower that in reality, so results are an upper bound on a validator's weight.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidatorPower {
+    pub operator_address: String,
+    pub moniker: String,
+    pub tokens: f64,
+    pub vote: ValidatorVote,
+}
+
+impl ValidatorPower {
+    // Bonded validators with their vote on the proposal, largest stake first
+    pub fn rank(validators: Vec<Validator>, votes: Vec<ValidatorVote>) -> Vec<ValidatorPower> {
+        let mut powers: Vec<ValidatorPower> = validators
+            .into_iter()
+            .zip(votes)
+            .map(|(validator, vote)| ValidatorPower {
+                tokens: validator.tokens.parse().unwrap_or(0.0),
+                operator_address: validator.operator_address,
+                moniker: validator.moniker,
+                vote,
+            })
+            .collect();
+        powers.sort_by(|a, b| b.tokens.total_cmp(&a.tokens));
+        powers
+    }
+}
+
+// The bonded validator set with each validator's vote on the proposal
+pub async fn fetch_validator_powers(chain: &ChainConfig, proposal_id: u64) -> Result<Vec<ValidatorPower>, String> {
+    let validators = governance::fetch_validators(&chain.lcd).await?;
+    let votes = governance::fetch_validator_votes(chain, proposal_id, &validators).await?;
+    Ok(ValidatorPower::rank(validators, votes))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hypothetical {
+    // The largest validators that haven't voted (yet, including earlier hypotheticals) vote `option`
+    TopNonVoters { count: usize, option: VoteOption },
+    // A given amount of stake, in base units, votes `option`
+    Stake { amount: f64, option: VoteOption },
+}
+
+impl Hypothetical {
+    pub fn describe(&self, format_amount: &dyn Fn(f64) -> String) -> String {
+        match self {
+            Hypothetical::TopNonVoters { count, option } => {
+                format!("Top {} validators that haven't voted vote {}", count, option.label())
+            }
+            Hypothetical::Stake { amount, option } => format!("{} more votes {}", format_amount(*amount), option.label()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Simulation {
+    pub tally: TallyResult,
+    // Validators the hypotheticals made vote, with the option and stake they added
+    pub added_validators: Vec<(String, VoteOption, f64)>,
+}
+
+pub fn simulate(current: &TallyResult, validators: &[ValidatorPower], hypotheticals: &[Hypothetical]) -> Simulation {
+    let mut amounts = [current.yes(), current.no(), current.abstain(), current.veto()];
+    let index = |option: VoteOption| VoteOption::ALL.iter().position(|o| *o == option).unwrap_or(0);
+    // Validators whose vote couldn't be looked up may already be in the tally, so they are never added
+    let mut voted: Vec<bool> = validators.iter().map(|v| v.vote != ValidatorVote::NotVoted).collect();
+    let mut added_validators = Vec::new();
+
+    for hypothetical in hypotheticals {
+        match hypothetical {
+            Hypothetical::TopNonVoters { count, option } => {
+                for (position, validator) in validators.iter().enumerate().filter(|(i, _)| !voted[*i]).take(*count).collect::<Vec<_>>() {
+                    voted[position] = true;
+                    amounts[index(*option)] += validator.tokens;
+                    added_validators.push((validator.moniker.clone(), *option, validator.tokens));
+                }
+            }
+            Hypothetical::Stake { amount, option } => amounts[index(*option)] += amount.max(0.0),
+        }
+    }
+
+    let [yes, no, abstain, veto] = amounts;
+    Simulation { tally: TallyResult::from_amounts(yes, no, abstain, veto), added_validators }
+}
+
+fn event_option(ev: &web_sys::Event) -> VoteOption {
+    let key = crate::event_select_value(ev);
+    VoteOption::ALL.into_iter().find(|o| o.key() == key).unwrap_or(VoteOption::Yes)
+}
+
+// Simulator panel for an open proposal; the validator set and their votes load on first use
+#[component]
+pub fn WhatIfSimulator(
+    cx: Scope,
+    proposal_id: u64,
+    chain: ChainConfig,
+    expedited: bool,
+    tally: Signal<Option<TallyResult>>,
+    context: Signal<Option<TallyContext>>,
+) -> impl IntoView {
+    let (open, set_open) = create_signal(cx, false);
+    let validators = create_rw_signal(cx, None::<Result<Vec<ValidatorPower>, String>>);
+    let hypotheticals = create_rw_signal(cx, Vec::<Hypothetical>::new());
+    let (by_validators, set_by_validators) = create_signal(cx, true);
+    let (count_input, set_count_input) = create_signal(cx, "10".to_string());
+    let (amount_input, set_amount_input) = create_signal(cx, String::new());
+    let (option, set_option) = create_signal(cx, VoteOption::No);
+    let (form_error, set_form_error) = create_signal(cx, None::<String>);
+
+    let load_chain = chain.clone();
+    let open_simulator = move |_| {
+        set_open.set(true);
+        if validators.get_untracked().is_some() {
+            return;
+        }
+        let chain = load_chain.clone();
+        spawn_local(async move {
+            let result = fetch_validator_powers(&chain, proposal_id).await;
+            if let Err(err) = &result {
+                log::error!("❌ Error loading validators for the simulator: {}", err);
+            }
+            validators.set(Some(result));
+        });
+    };
+
+    let form_chain = chain.clone();
+    let add_hypothetical = move |_| {
+        let hypothetical = if by_validators.get_untracked() {
+            match count_input.get_untracked().trim().parse::<usize>() {
+                Ok(count) if count > 0 => Hypothetical::TopNonVoters { count, option: option.get_untracked() },
+                _ => {
+                    set_form_error.set(Some("Enter how many validators, e.g. 10".to_string()));
+                    return;
+                }
+            }
+        } else {
+            match account::parse_amount(&amount_input.get_untracked(), &form_chain) {
+                Ok(amount) if amount > 0 => Hypothetical::Stake { amount: amount as f64, option: option.get_untracked() },
+                Ok(_) => {
+                    set_form_error.set(Some("Enter an amount above zero".to_string()));
+                    return;
+                }
+                Err(err) => {
+                    set_form_error.set(Some(err));
+                    return;
+                }
+            }
+        };
+        set_form_error.set(None);
+        hypotheticals.update(|list| list.push(hypothetical));
+    };
+
+    let view_chain = chain;
+    view! { cx,
+        <div class="simulator">
+            <h3>"What-if simulator"</h3>
+            {move || {
+                let chain = view_chain.clone();
+                if !open.get() {
+                    return view! { cx,
+                        <button class="vote-button" on:click=open_simulator.clone()>"🔮 Simulate hypothetical votes"</button>
+                    }.into_view(cx);
+                }
+                let powers = match validators.get() {
+                    None => return view! { cx, <p>"Loading validators and their votes..."</p> }.into_view(cx),
+                    Some(Err(err)) => return view! { cx, <p class="watch-error">{format!("Could not load validators: {}", err)}</p> }.into_view(cx),
+                    Some(Ok(powers)) => powers,
+                };
+                let Some(current) = tally.get() else {
+                    return view! { cx, <p>"Waiting for the live tally..."</p> }.into_view(cx);
+                };
+                let not_voted = powers.iter().filter(|v| v.vote == ValidatorVote::NotVoted).count();
+                let unknown = powers.iter().filter(|v| matches!(v.vote, ValidatorVote::Unknown(_))).count();
+                let list = hypotheticals.get();
+                let simulation = simulate(&current, &powers, &list);
+                let amount_chain = chain.clone();
+                let format_amount = move |amount: f64| account::format_amount(amount, &amount_chain.denom, &amount_chain);
+                let rules = list.iter().enumerate().map(|(position, hypothetical)| view! { cx,
+                    <li>
+                        {hypothetical.describe(&format_amount)}
+                        " "
+                        <button class="read-more" on:click=move |_| hypotheticals.update(|list| {
+                            list.remove(position);
+                        })>"✕"</button>
+                    </li>
+                }).collect::<Vec<_>>();
+                let added = simulation.added_validators.iter().map(|(moniker, option, tokens)| view! { cx,
+                    <li>{format!("{}: {} ({})", moniker, option.label(), format_amount(*tokens))}</li>
+                }).collect::<Vec<_>>();
+                view! { cx,
+                    <div>
+                        <p>{format!("{} of {} bonded validators haven't voted yet.", not_voted, powers.len())}</p>
+                        {(unknown > 0).then(|| view! { cx,
+                            <p class="watch-error">{format!("The votes of {} validators couldn't be looked up; they are left out of the hypotheticals.", unknown)}</p>
+                        })}
+                        <div class="simulator-form">
+                            <select class="chain-select" on:change=move |ev| set_by_validators.set(crate::event_select_value(&ev) == "validators")>
+                                <option value="validators" selected=by_validators.get_untracked()>"Top validators that haven't voted"</option>
+                                <option value="stake" selected=!by_validators.get_untracked()>"An amount of stake"</option>
+                            </select>
+                            {move || if by_validators.get() {
+                                view! { cx,
+                                    <input class="watch-input" type="number" min="1" placeholder="Validators"
+                                        prop:value=count_input on:input=move |ev| set_count_input.set(event_target_value(&ev)) />
+                                }
+                            } else {
+                                view! { cx,
+                                    <input class="watch-input" type="number" min="0" placeholder="Amount"
+                                        prop:value=amount_input on:input=move |ev| set_amount_input.set(event_target_value(&ev)) />
+                                }
+                            }}
+                            <select class="chain-select" prop:value=move || option.get().key() on:change=move |ev| set_option.set(event_option(&ev))>
+                                {VoteOption::ALL.iter().map(|o| view! { cx, <option value={o.key()}>{o.label()}</option> }).collect::<Vec<_>>()}
+                            </select>
+                            <button class="vote-button" on:click=add_hypothetical.clone()>"Add"</button>
+                            <button class="vote-button" on:click=move |_| hypotheticals.set(Vec::new())>"Reset"</button>
+                        </div>
+                        {move || form_error.get().map(|err| view! { cx, <p class="watch-error">{err}</p> })}
+                        <ul class="simulator-rules">{rules}</ul>
+                        {crate::render_tally(cx, &simulation.tally, context.get().as_ref(), TallyKind::Simulated, expedited, &chain)}
+                        {(!added.is_empty()).then(|| view! { cx,
+                            <details>
+                                <summary>{format!("{} validators added", added.len())}</summary>
+                                <ul>{added}</ul>
+                            </details>
+                        })}
+                        <p class="simulator-note">"Validators count with their whole stake; delegators who voted themselves would lower that."</p>
+                    </div>
+                }.into_view(cx)
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Gov proposal status as reported by the API, with labels, styles and grouping in one place
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProposalStatus {
+    DepositPeriod,
+    VotingPeriod,
+    Passed,
+    Rejected,
+    // Passed, but its messages failed to execute
+    Failed,
+    Unspecified,
+    // Anything this app doesn't know yet, kept as the API sent it
+    Other(String),
+}
+
+// Where a status sits in a proposal's life, in the order the Vote view groups them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StatusGroup {
+    Active,
+    Ended,
+    Unknown,
+}
+
+impl ProposalStatus {
+    // Statuses offered by the Vote view's filter, in display order
+    pub const KNOWN: [ProposalStatus; 5] = [
+        ProposalStatus::VotingPeriod,
+        ProposalStatus::DepositPeriod,
+        ProposalStatus::Passed,
+        ProposalStatus::Rejected,
+        ProposalStatus::Failed,
+    ];
+
+    pub fn from_proto(value: &str) -> ProposalStatus {
+        match value.trim() {
+            "PROPOSAL_STATUS_DEPOSIT_PERIOD" => ProposalStatus::DepositPeriod,
+            "PROPOSAL_STATUS_VOTING_PERIOD" => ProposalStatus::VotingPeriod,
+            "PROPOSAL_STATUS_PASSED" => ProposalStatus::Passed,
+            "PROPOSAL_STATUS_REJECTED" => ProposalStatus::Rejected,
+            "PROPOSAL_STATUS_FAILED" => ProposalStatus::Failed,
+            "PROPOSAL_STATUS_UNSPECIFIED" | "" => ProposalStatus::Unspecified,
+            other => ProposalStatus::Other(other.to_string()),
+        }
+    }
+
+    // The proto enum name, e.g. "PROPOSAL_STATUS_PASSED"
+    pub fn proto_name(&self) -> &str {
+        match self {
+            ProposalStatus::DepositPeriod => "PROPOSAL_STATUS_DEPOSIT_PERIOD",
+            ProposalStatus::VotingPeriod => "PROPOSAL_STATUS_VOTING_PERIOD",
+            ProposalStatus::Passed => "PROPOSAL_STATUS_PASSED",
+            ProposalStatus::Rejected => "PROPOSAL_STATUS_REJECTED",
+            ProposalStatus::Failed => "PROPOSAL_STATUS_FAILED",
+            ProposalStatus::Unspecified => "PROPOSAL_STATUS_UNSPECIFIED",
+            ProposalStatus::Other(other) => other,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            ProposalStatus::DepositPeriod => "Deposit Period".to_string(),
+            ProposalStatus::VotingPeriod => "Voting Period".to_string(),
+            ProposalStatus::Passed => "Passed".to_string(),
+            ProposalStatus::Rejected => "Rejected".to_string(),
+            ProposalStatus::Failed => "Failed".to_string(),
+            ProposalStatus::Unspecified => "Unknown Status".to_string(),
+            // e.g. "PROPOSAL_STATUS_SOMETHING_NEW" -> "Something New"
+            ProposalStatus::Other(other) => other
+                .trim_start_matches("PROPOSAL_STATUS_")
+                .split('_')
+                .filter(|word| !word.is_empty())
+                .map(|word| {
+                    let word = word.to_lowercase();
+                    let mut chars = word.chars();
+                    chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            ProposalStatus::DepositPeriod => "deposit",
+            ProposalStatus::VotingPeriod => "voting",
+            ProposalStatus::Passed => "passed",
+            ProposalStatus::Rejected => "rejected",
+            ProposalStatus::Failed => "failed",
+            ProposalStatus::Unspecified | ProposalStatus::Other(_) => "default",
+        }
+    }
+
+    pub fn group(&self) -> StatusGroup {
+        match self {
+            ProposalStatus::DepositPeriod | ProposalStatus::VotingPeriod => StatusGroup::Active,
+            ProposalStatus::Passed | ProposalStatus::Rejected | ProposalStatus::Failed => StatusGroup::Ended,
+            ProposalStatus::Unspecified | ProposalStatus::Other(_) => StatusGroup::Unknown,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.group() == StatusGroup::Active
+    }
+
+    pub fn has_ended(&self) -> bool {
+        self.group() == StatusGroup::Ended
+    }
+
+    // Outcome of the vote once it has ended; Failed proposals passed the vote
+    pub fn passed(&self) -> Option<bool> {
+        match self {
+            ProposalStatus::Passed | ProposalStatus::Failed => Some(true),
+            ProposalStatus::Rejected => Some(false),
+            _ => None,
+        }
+    }
+
+    // Sort key for grouping by state: voting, deposit, then the outcomes
+    pub fn rank(&self) -> (StatusGroup, usize) {
+        let position = Self::KNOWN.iter().position(|s| s == self).unwrap_or(Self::KNOWN.len());
+        (self.group(), position)
+    }
+}
+
+impl Serialize for ProposalStatus {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.proto_name())
+    }
+}
+
+impl<'de> Deserialize<'de> for ProposalStatus {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(ProposalStatus::from_proto(&String::deserialize(deserializer)?))
+    }
+}
@@ -0,0 +1,28 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Thin JSON wrapper around window.localStorage
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+}
+
+pub fn load<T: DeserializeOwned>(key: &str) -> Option<T> {
+    local_storage()
+        .and_then(|storage| storage.get_item(key).ok().flatten())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+}
+
+pub fn save<T: Serialize + ?Sized>(key: &str, value: &T) {
+    if let (Some(storage), Ok(raw)) = (local_storage(), serde_json::to_string(value)) {
+        if let Err(err) = storage.set_item(key, &raw) {
+            log::error!("❌ Failed to save {}: {:?}", key, err);
+        }
+    }
+}
+
+pub fn remove(key: &str) {
+    if let Some(storage) = local_storage() {
+        storage.remove_item(key).ok();
+    }
+}
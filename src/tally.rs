@@ -0,0 +1,128 @@
+use serde::Deserialize;
+use wasm_bindgen::JsValue;
+
+use crate::chains::ChainConfig;
+
+// Vote totals in the staking denom. gov v1 names them `yes_count` etc., v1beta1 just `yes`.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct TallyResult {
+    #[serde(default, alias = "yes")]
+    pub yes_count: String,
+    #[serde(default, alias = "no")]
+    pub no_count: String,
+    #[serde(default, alias = "abstain")]
+    pub abstain_count: String,
+    #[serde(default, alias = "no_with_veto")]
+    pub no_with_veto_count: String,
+}
+
+fn amount(value: &str) -> f64 {
+    value.parse::<f64>().unwrap_or(0.0)
+}
+
+fn ratio(part: f64, whole: f64) -> f64 {
+    if whole > 0.0 {
+        part / whole
+    } else {
+        0.0
+    }
+}
+
+impl TallyResult {
+    pub fn from_amounts(yes: f64, no: f64, abstain: f64, veto: f64) -> TallyResult {
+        TallyResult {
+            yes_count: format!("{:.0}", yes),
+            no_count: format!("{:.0}", no),
+            abstain_count: format!("{:.0}", abstain),
+            no_with_veto_count: format!("{:.0}", veto),
+        }
+    }
+
+    pub fn yes(&self) -> f64 {
+        amount(&self.yes_count)
+    }
+
+    pub fn no(&self) -> f64 {
+        amount(&self.no_count)
+    }
+
+    pub fn abstain(&self) -> f64 {
+        amount(&self.abstain_count)
+    }
+
+    pub fn veto(&self) -> f64 {
+        amount(&self.no_with_veto_count)
+    }
+
+    pub fn total(&self) -> f64 {
+        self.yes() + self.no() + self.abstain() + self.veto()
+    }
+}
+
+// Chain-wide inputs to the pass/fail rules: gov tally params plus bonded stake
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TallyContext {
+    pub quorum: String,
+    pub threshold: String,
+    // Yes share expedited proposals need; missing before SDK 0.50
+    #[serde(default)]
+    pub expedited_threshold: String,
+    pub veto_threshold: String,
+    pub bonded_tokens: String,
+}
+
+// A tally measured against the chain's rules, all values as fractions of 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct TallyProgress {
+    pub turnout: f64,
+    pub quorum: f64,
+    pub yes_share: f64,
+    pub threshold: f64,
+    pub veto_share: f64,
+    pub veto_threshold: f64,
+}
+
+impl TallyProgress {
+    // Cosmos SDK rules: turnout reaches quorum, vetoes stay at or under the veto threshold
+    // and Yes beats the threshold (the expedited one for expedited proposals) among non-abstaining votes
+    pub fn measure(tally: &TallyResult, context: &TallyContext, expedited: bool) -> TallyProgress {
+        let total = tally.total();
+        let threshold = if expedited && !context.expedited_threshold.is_empty() { &context.expedited_threshold } else { &context.threshold };
+        TallyProgress {
+            turnout: ratio(total, amount(&context.bonded_tokens)),
+            quorum: amount(&context.quorum),
+            yes_share: ratio(tally.yes(), total - tally.abstain()),
+            threshold: amount(threshold),
+            veto_share: ratio(tally.veto(), total),
+            veto_threshold: amount(&context.veto_threshold),
+        }
+    }
+
+    pub fn reaches_quorum(&self) -> bool {
+        self.turnout >= self.quorum
+    }
+
+    pub fn vetoed(&self) -> bool {
+        self.veto_share > self.veto_threshold
+    }
+
+    pub fn would_pass(&self) -> bool {
+        self.reaches_quorum() && !self.vetoed() && self.yes_share > self.threshold
+    }
+}
+
+pub fn format_percent(fraction: f64) -> String {
+    format!("{:.2}%", fraction * 100.0)
+}
+
+pub async fn fetch_tally_context(chain: &ChainConfig) -> Result<TallyContext, String> {
+    crate::call_js_async("fetchTallyContext", &[JsValue::from_str(&chain.lcd)]).await
+}
+
+pub async fn fetch_live_tally(chain: &ChainConfig, proposal_id: u64) -> Result<TallyResult, String> {
+    crate::call_js_async(
+        "fetchProposalTally",
+        &[JsValue::from_str(&chain.lcd), JsValue::from_str(&proposal_id.to_string())],
+    )
+    .await
+}
@@ -0,0 +1,232 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use wasm_bindgen::JsValue;
+
+// Chain timestamps (RFC 3339) as milliseconds since the Unix epoch, so they don't depend on
+// what the browser's Date parser accepts
+
+const MS_PER_DAY: i64 = 86_400_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(i64);
+
+// How timestamps are displayed, chosen on the Vote view
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeZone {
+    #[default]
+    Local,
+    Utc,
+}
+
+impl TimeZone {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeZone::Local => "Local time",
+            TimeZone::Utc => "UTC",
+        }
+    }
+
+    pub fn toggled(&self) -> TimeZone {
+        match self {
+            TimeZone::Local => TimeZone::Utc,
+            TimeZone::Utc => TimeZone::Local,
+        }
+    }
+}
+
+// Days since 1970-01-01 to (year, month, day), Howard Hinnant's civil_from_days
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// The inverse: (year, month, day) to days since 1970-01-01
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn number(value: &str, field: &str) -> Result<u32, String> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("invalid {} '{}'", field, value));
+    }
+    value.parse().map_err(|_| format!("invalid {} '{}'", field, value))
+}
+
+impl Timestamp {
+    pub fn from_millis(ms: i64) -> Timestamp {
+        Timestamp(ms)
+    }
+
+    pub fn now() -> Timestamp {
+        Timestamp(js_sys::Date::now() as i64)
+    }
+
+    pub fn millis(&self) -> i64 {
+        self.0
+    }
+
+    // e.g. 2024-05-01T12:00:00Z or 2024-05-01T14:00:00.123456789+02:00
+    pub fn parse(value: &str) -> Result<Timestamp, String> {
+        let value = value.trim();
+        if value.len() < 20 || !value.is_ascii() {
+            return Err(format!("'{}' is not an RFC 3339 timestamp", value));
+        }
+        let (date, rest) = value.split_at(10);
+        let date_parts: Vec<&str> = date.split('-').collect();
+        if date_parts.len() != 3 || !matches!(rest.as_bytes()[0], b'T' | b't' | b' ') {
+            return Err(format!("'{}' is not an RFC 3339 timestamp", value));
+        }
+        let year = i64::from(number(date_parts[0], "year")?);
+        let month = number(date_parts[1], "month")?;
+        let day = number(date_parts[2], "day")?;
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(format!("invalid date '{}'", date));
+        }
+
+        let rest = &rest[1..];
+        let (clock, rest) = rest.split_at(8.min(rest.len()));
+        let clock_parts: Vec<&str> = clock.split(':').collect();
+        if clock_parts.len() != 3 {
+            return Err(format!("invalid time '{}'", clock));
+        }
+        let hour = number(clock_parts[0], "hour")?;
+        let minute = number(clock_parts[1], "minute")?;
+        // 60 is a leap second, folded into the next minute
+        let second = number(clock_parts[2], "second")?;
+        if hour > 23 || minute > 59 || second > 60 {
+            return Err(format!("invalid time '{}'", clock));
+        }
+
+        // Optional fraction; only milliseconds are kept
+        let (millis, offset) = match rest.strip_prefix('.') {
+            Some(fraction) => {
+                let digits = fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+                if digits == 0 {
+                    return Err(format!("invalid fraction in '{}'", value));
+                }
+                let ms = format!("{:0<3}", &fraction[..digits.min(3)]);
+                (number(&ms, "fraction")?, &fraction[digits..])
+            }
+            None => (0, rest),
+        };
+        let offset_minutes = match offset {
+            "Z" | "z" => 0,
+            _ => {
+                let sign = match offset.as_bytes().first() {
+                    Some(b'+') => 1,
+                    Some(b'-') => -1,
+                    _ => return Err(format!("missing time zone in '{}'", value)),
+                };
+                let (hours, minutes) = offset[1..].split_once(':').ok_or_else(|| format!("invalid offset '{}'", offset))?;
+                let (hours, minutes) = (number(hours, "offset")?, number(minutes, "offset")?);
+                if hours > 23 || minutes > 59 {
+                    return Err(format!("invalid offset '{}'", offset));
+                }
+                sign * i64::from(hours * 60 + minutes)
+            }
+        };
+
+        let seconds = i64::from(hour * 3600 + minute * 60 + second) - offset_minutes * 60;
+        Ok(Timestamp(days_from_civil(year, month, day) * MS_PER_DAY + seconds * 1000 + i64::from(millis)))
+    }
+
+    // Minutes to add to UTC for the browser's local time at this instant (DST aware)
+    fn local_offset_minutes(&self) -> i64 {
+        -js_sys::Date::new(&JsValue::from_f64(self.0 as f64)).get_timezone_offset() as i64
+    }
+
+    fn civil(&self, offset_minutes: i64) -> String {
+        let ms = self.0 + offset_minutes * 60_000;
+        let (year, month, day) = civil_from_days(ms.div_euclid(MS_PER_DAY));
+        let secs_of_day = ms.rem_euclid(MS_PER_DAY) / 1000;
+        format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            secs_of_day / 3600,
+            secs_of_day % 3600 / 60,
+            secs_of_day % 60
+        )
+    }
+
+    // e.g. "2024-05-01 12:00:00 UTC" or "2024-05-01 14:00:00 (UTC+02:00)"
+    pub fn format(&self, zone: TimeZone) -> String {
+        match zone {
+            TimeZone::Utc => format!("{} UTC", self.civil(0)),
+            TimeZone::Local => {
+                let offset = self.local_offset_minutes();
+                let sign = if offset < 0 { '-' } else { '+' };
+                format!("{} (UTC{}{:02}:{:02})", self.civil(offset), sign, offset.abs() / 60, offset.abs() % 60)
+            }
+        }
+    }
+
+    // YYYY-MM-DD in UTC, comparable with <input type="date"> values
+    pub fn utc_date(&self) -> String {
+        self.civil(0)[..10].to_string()
+    }
+}
+
+// Time left until a deadline: days/hours/minutes, then minutes and seconds in the final hour
+pub fn format_countdown(remaining_ms: i64) -> String {
+    let seconds = remaining_ms.max(0) / 1000;
+    let (days, hours, minutes) = (seconds / 86_400, seconds % 86_400 / 3600, seconds % 3600 / 60);
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m {:02}s", minutes, seconds % 60)
+    }
+}
+
+// Missing, malformed or zero ("0001-01-01T00:00:00Z", i.e. not set yet) timestamps become None
+// instead of failing the whole proposal
+pub fn deserialize_optional<'de, D>(deserializer: D) -> Result<Option<Timestamp>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value: Option<String> = Option::deserialize(deserializer)?;
+    Ok(value.and_then(|value| match Timestamp::parse(&value) {
+        Ok(timestamp) if !value.starts_with("0001-") => Some(timestamp),
+        Ok(_) => None,
+        Err(err) => {
+            log::warn!("⚠️ Ignoring timestamp: {}", err);
+            None
+        }
+    }))
+}
+
+// localStorage key for the local/UTC choice
+const TIME_ZONE_STORAGE_KEY: &str = "yolonode.time_zone";
+
+pub fn load_time_zone() -> TimeZone {
+    crate::storage::load(TIME_ZONE_STORAGE_KEY).unwrap_or_default()
+}
+
+pub fn save_time_zone(zone: TimeZone) {
+    crate::storage::save(TIME_ZONE_STORAGE_KEY, &zone);
+}
@@ -0,0 +1,167 @@
+use leptos::*;
+
+use crate::account;
+use crate::chains::ChainConfig;
+use crate::governance::{ValidatorVote, YOLONODE_VALIDATOR};
+use crate::simulator::{self, ValidatorPower};
+use crate::tally::format_percent;
+
+// How every bonded validator voted on an open proposal, so delegators can tell whether to
+// override the vote they inherit
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValidatorFilter {
+    All,
+    Delegated,
+    NotVoted,
+}
+
+impl ValidatorFilter {
+    const ALL: [ValidatorFilter; 3] = [ValidatorFilter::All, ValidatorFilter::Delegated, ValidatorFilter::NotVoted];
+
+    fn key(&self) -> &'static str {
+        match self {
+            ValidatorFilter::All => "all",
+            ValidatorFilter::Delegated => "delegated",
+            ValidatorFilter::NotVoted => "not-voted",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ValidatorFilter::All => "All validators",
+            ValidatorFilter::Delegated => "My validators",
+            ValidatorFilter::NotVoted => "Not voted yet",
+        }
+    }
+
+    fn matches(&self, validator: &ValidatorPower, delegations: &[String]) -> bool {
+        match self {
+            ValidatorFilter::All => true,
+            ValidatorFilter::Delegated => delegations.contains(&validator.operator_address),
+            ValidatorFilter::NotVoted => validator.vote == ValidatorVote::NotVoted,
+        }
+    }
+}
+
+// Vote table for an open proposal; `delegations` are the connected wallet's validators (valoper)
+#[component]
+pub fn ValidatorVotes(cx: Scope, proposal_id: u64, chain: ChainConfig, delegations: Signal<Vec<String>>) -> impl IntoView {
+    let (open, set_open) = create_signal(cx, false);
+    let validators = create_rw_signal(cx, None::<Result<Vec<ValidatorPower>, String>>);
+    let (filter, set_filter) = create_signal(cx, ValidatorFilter::All);
+
+    let load_chain = chain.clone();
+    let open_table = move |_| {
+        set_open.set(true);
+        if validators.get_untracked().is_some() {
+            return;
+        }
+        let chain = load_chain.clone();
+        spawn_local(async move {
+            let result = simulator::fetch_validator_powers(&chain, proposal_id).await;
+            if let Err(err) = &result {
+                log::error!("❌ Error loading validator votes on proposal #{}: {}", proposal_id, err);
+            }
+            validators.set(Some(result));
+        });
+    };
+
+    view! { cx,
+        <div class="validator-votes">
+            <h3>"Validator votes"</h3>
+            {move || {
+                if !open.get() {
+                    return view! { cx,
+                        <button class="vote-button" on:click=open_table.clone()>"🗳️ Show how validators voted"</button>
+                    }.into_view(cx);
+                }
+                let powers = match validators.get() {
+                    None => return view! { cx, <p>"Loading validators and their votes..."</p> }.into_view(cx),
+                    Some(Err(err)) => return view! { cx, <p class="watch-error">{format!("Could not load validator votes: {}", err)}</p> }.into_view(cx),
+                    Some(Ok(powers)) => powers,
+                };
+                let bonded: f64 = powers.iter().map(|v| v.tokens).sum();
+                let share = move |tokens: f64| if bonded > 0.0 { format_percent(tokens / bonded) } else { "—".to_string() };
+                let voted: Vec<&ValidatorPower> = powers.iter().filter(|v| v.vote.vote().is_some()).collect();
+                let voted_power: f64 = voted.iter().map(|v| v.tokens).sum();
+                let not_voted_power: f64 = powers.iter().filter(|v| v.vote == ValidatorVote::NotVoted).map(|v| v.tokens).sum();
+                let unknown = powers.iter().filter(|v| matches!(v.vote, ValidatorVote::Unknown(_))).count();
+                let delegations = delegations.get();
+                let current = filter.get();
+                let rows = powers
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, v)| current.matches(v, &delegations))
+                    .map(|(position, validator)| {
+                        let mut classes = Vec::new();
+                        if validator.operator_address == YOLONODE_VALIDATOR {
+                            classes.push("yolonode-validator");
+                        }
+                        if delegations.contains(&validator.operator_address) {
+                            classes.push("delegated-validator");
+                        }
+                        let vote_class = match validator.vote.vote().and_then(|v| v.main_option()) {
+                            Some(option) => format!("tally-option {}", option.css_class()),
+                            None => "no-vote".to_string(),
+                        };
+                        let title = match &validator.vote {
+                            ValidatorVote::Unknown(err) => format!("Lookup failed: {}", err),
+                            _ => String::new(),
+                        };
+                        view! { cx,
+                            <tr class={classes.join(" ")}>
+                                <td>{position + 1}</td>
+                                <td>{validator.moniker.clone()}</td>
+                                <td>{account::format_amount(validator.tokens, &chain.denom, &chain)}</td>
+                                <td>{share(validator.tokens)}</td>
+                                <td class={vote_class} title=title>{validator.vote.describe()}</td>
+                            </tr>
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let no_rows = rows.is_empty();
+                let needs_wallet = current == ValidatorFilter::Delegated && delegations.is_empty();
+                view! { cx,
+                    <div>
+                        <p>{format!(
+                            "{} of {} bonded validators voted, holding {} of the voting power. Non-voters hold {}.",
+                            voted.len(),
+                            powers.len(),
+                            share(voted_power),
+                            share(not_voted_power)
+                        )}</p>
+                        {(unknown > 0).then(|| view! { cx,
+                            <p class="watch-error">{format!("The votes of {} validators couldn't be looked up and are shown as Unknown.", unknown)}</p>
+                        })}
+                        <select class="chain-select" on:change=move |ev| {
+                            let key = crate::event_select_value(&ev);
+                            set_filter.set(ValidatorFilter::ALL.into_iter().find(|f| f.key() == key).unwrap_or(ValidatorFilter::All));
+                        }>
+                            {ValidatorFilter::ALL.iter().map(|f| view! { cx,
+                                <option value={f.key()} selected={*f == current}>{f.label()}</option>
+                            }).collect::<Vec<_>>()}
+                        </select>
+                        {needs_wallet.then(|| view! { cx,
+                            <p>"Connect a wallet that delegates on this chain to see its validators."</p>
+                        })}
+                        {(no_rows && !needs_wallet).then(|| view! { cx,
+                            <p>"No validators match."</p>
+                        })}
+                        <table class="message-fields validator-votes-table">
+                            <tr>
+                                <th>"#"</th>
+                                <th>"Validator"</th>
+                                <th>"Stake"</th>
+                                <th>"Voting power"</th>
+                                <th>"Vote"</th>
+                            </tr>
+                            {rows}
+                        </table>
+                        <p class="simulator-note">"Delegators inherit their validator's vote unless they vote themselves."</p>
+                    </div>
+                }.into_view(cx)
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,407 @@
+use gloo_utils::format::JsValueSerdeExt;
+use js_sys::{Array, Function, Object, Promise, Reflect, Uint8Array};
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{future_to_promise, JsFuture};
+
+use crate::storage;
+
+// localStorage key remembering which wallet the user last connected with
+const WALLET_STORAGE_KEY: &str = "yolonode.wallet";
+
+// Browser wallets we know how to talk to, each injected under its own global
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletKind {
+    Keplr,
+    Leap,
+    Cosmostation,
+}
+
+impl WalletKind {
+    pub const ALL: [WalletKind; 3] = [WalletKind::Keplr, WalletKind::Leap, WalletKind::Cosmostation];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            WalletKind::Keplr => "Keplr / Fina",
+            WalletKind::Leap => "Leap",
+            WalletKind::Cosmostation => "Cosmostation",
+        }
+    }
+
+    pub fn install_url(&self) -> &'static str {
+        match self {
+            WalletKind::Keplr => "https://www.keplr.app/",
+            WalletKind::Leap => "https://www.leapwallet.io/",
+            WalletKind::Cosmostation => "https://www.cosmostation.io/products/cosmostation_extension",
+        }
+    }
+
+    pub fn provider(&self) -> &'static dyn WalletProvider {
+        match self {
+            WalletKind::Keplr => &KEPLR,
+            WalletKind::Leap => &LEAP,
+            WalletKind::Cosmostation => &COSMOSTATION,
+        }
+    }
+
+    pub fn is_installed(&self) -> bool {
+        self.provider().injected().is_some()
+    }
+}
+
+// Account details normalized across providers
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletAccount {
+    pub address: String,
+    pub name: Option<String>,
+    pub pub_key: Vec<u8>,
+}
+
+// Amino signing result in Keplr's shape ({ signed, signature: { pub_key, signature } })
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AminoSignResponse {
+    pub signed: serde_json::Value,
+    pub signature: serde_json::Value,
+}
+
+// Normalized interface over the injected wallet objects. Methods hand back the raw JS
+// promise; the async helpers below await them and parse the provider-specific result.
+pub trait WalletProvider {
+    fn kind(&self) -> WalletKind;
+
+    // The injected wallet object, if the extension is installed
+    fn injected(&self) -> Option<JsValue>;
+
+    fn enable(&self, chain_id: &str) -> Result<Promise, String>;
+
+    fn request_account(&self, chain_id: &str) -> Result<Promise, String>;
+
+    fn parse_account(&self, raw: &JsValue) -> Result<WalletAccount, String>;
+
+    fn request_sign_amino(&self, chain_id: &str, signer: &str, sign_doc: &JsValue) -> Result<Promise, String>;
+
+    fn parse_sign_response(&self, raw: JsValue) -> Result<AminoSignResponse, String>;
+
+    // Window event the extension dispatches when the user switches accounts
+    fn account_change_event(&self) -> &'static str;
+
+    // Offline signer usable by secretjs. Defaults to an amino-only one built on the
+    // normalized account and signing calls above.
+    fn get_offline_signer(&self, chain_id: &str) -> Result<JsValue, String> {
+        Ok(build_offline_signer(self.kind(), chain_id))
+    }
+
+    // Whether the offline signer can sign direct (protobuf) transactions, which proposal
+    // submissions need because their messages have no amino encoding in secretjs
+    fn signs_direct(&self) -> bool {
+        false
+    }
+}
+
+// Enable the chain in the wallet (prompts the user on first use)
+pub async fn enable(provider: &dyn WalletProvider, chain_id: &str) -> Result<(), String> {
+    await_promise(provider.enable(chain_id)?).await.map(|_| ())
+}
+
+pub async fn get_account(provider: &dyn WalletProvider, chain_id: &str) -> Result<WalletAccount, String> {
+    let raw = await_promise(provider.request_account(chain_id)?).await?;
+    provider.parse_account(&raw)
+}
+
+pub async fn sign_amino(
+    provider: &dyn WalletProvider,
+    chain_id: &str,
+    signer: &str,
+    sign_doc: &JsValue,
+) -> Result<AminoSignResponse, String> {
+    let raw = await_promise(provider.request_sign_amino(chain_id, signer, sign_doc)?).await?;
+    provider.parse_sign_response(raw)
+}
+
+// Enable the chain and return the connected address in one step
+pub async fn connect(provider: &dyn WalletProvider, chain_id: &str) -> Result<String, String> {
+    enable(provider, chain_id).await?;
+    get_account(provider, chain_id).await.map(|account| account.address)
+}
+
+async fn await_promise(promise: Promise) -> Result<JsValue, String> {
+    JsFuture::from(promise)
+        .await
+        .map_err(|err| err.as_string().unwrap_or_else(|| format!("{:?}", err)))
+}
+
+fn get_path(root: &JsValue, path: &[&str]) -> Option<JsValue> {
+    path.iter().try_fold(root.clone(), |value, key| {
+        Reflect::get(&value, &JsValue::from_str(key))
+            .ok()
+            .filter(|v| !v.is_undefined() && !v.is_null())
+    })
+}
+
+fn window_global(path: &[&str]) -> Option<JsValue> {
+    web_sys::window().and_then(|w| get_path(&w.into(), path))
+}
+
+// Call `method` on `target` with the given arguments
+fn call_method(target: &JsValue, method: &str, args: &[JsValue]) -> Result<JsValue, String> {
+    let func = get_path(target, &[method])
+        .and_then(|f| f.dyn_into::<Function>().ok())
+        .ok_or_else(|| format!("{} is not available in this wallet", method))?;
+    func.apply(target, &args.iter().collect::<Array>())
+        .map_err(|err| format!("{} failed: {:?}", method, err))
+}
+
+fn call_promise(target: &JsValue, method: &str, args: &[JsValue]) -> Result<Promise, String> {
+    call_method(target, method, args)?
+        .dyn_into::<Promise>()
+        .map_err(|_| format!("{} did not return a Promise", method))
+}
+
+fn get_string(value: &JsValue, key: &str) -> Option<String> {
+    get_path(value, &[key]).and_then(|v| v.as_string())
+}
+
+fn get_bytes(value: &JsValue, key: &str) -> Vec<u8> {
+    get_path(value, &[key])
+        .and_then(|v| v.dyn_into::<Uint8Array>().ok())
+        .map(|bytes| bytes.to_vec())
+        .unwrap_or_default()
+}
+
+// Offline signer object ({ getAccounts, signAmino }) backed by a provider's normalized calls
+fn build_offline_signer(kind: WalletKind, chain_id: &str) -> JsValue {
+    let signer = Object::new();
+
+    let accounts_chain = chain_id.to_string();
+    let get_accounts = Closure::wrap(Box::new(move || {
+        let chain_id = accounts_chain.clone();
+        future_to_promise(async move {
+            let account = get_account(kind.provider(), &chain_id).await.map_err(|e| JsValue::from_str(&e))?;
+            let entry = Object::new();
+            Reflect::set(&entry, &"address".into(), &account.address.into())?;
+            Reflect::set(&entry, &"algo".into(), &"secp256k1".into())?;
+            Reflect::set(&entry, &"pubkey".into(), &Uint8Array::from(account.pub_key.as_slice()).into())?;
+            Ok(Array::of1(&entry).into())
+        })
+    }) as Box<dyn Fn() -> Promise>);
+
+    let sign_chain = chain_id.to_string();
+    let sign = Closure::wrap(Box::new(move |signer_address: String, sign_doc: JsValue| {
+        let chain_id = sign_chain.clone();
+        future_to_promise(async move {
+            let response = sign_amino(kind.provider(), &chain_id, &signer_address, &sign_doc)
+                .await
+                .map_err(|e| JsValue::from_str(&e))?;
+            JsValue::from_serde(&response).map_err(|e| JsValue::from_str(&e.to_string()))
+        })
+    }) as Box<dyn Fn(String, JsValue) -> Promise>);
+
+    Reflect::set(&signer, &"getAccounts".into(), get_accounts.as_ref()).ok();
+    Reflect::set(&signer, &"signAmino".into(), sign.as_ref()).ok();
+
+    // The signer is handed to JS, which may hold on to it for the lifetime of a client
+    get_accounts.forget();
+    sign.forget();
+
+    signer.into()
+}
+
+// Keplr and Leap expose the same Keplr API, just under different globals
+pub struct KeplrCompatible {
+    kind: WalletKind,
+    global: &'static str,
+    account_event: &'static str,
+}
+
+pub static KEPLR: KeplrCompatible = KeplrCompatible {
+    kind: WalletKind::Keplr,
+    global: "keplr",
+    account_event: "keplr_keystorechange",
+};
+pub static LEAP: KeplrCompatible = KeplrCompatible {
+    kind: WalletKind::Leap,
+    global: "leap",
+    account_event: "leap_keystorechange",
+};
+pub static COSMOSTATION: Cosmostation = Cosmostation;
+
+impl KeplrCompatible {
+    fn wallet(&self) -> Result<JsValue, String> {
+        self.injected().ok_or_else(|| format!("{} is not installed", self.kind.name()))
+    }
+}
+
+impl WalletProvider for KeplrCompatible {
+    fn kind(&self) -> WalletKind {
+        self.kind
+    }
+
+    fn injected(&self) -> Option<JsValue> {
+        window_global(&[self.global])
+    }
+
+    fn enable(&self, chain_id: &str) -> Result<Promise, String> {
+        call_promise(&self.wallet()?, "enable", &[chain_id.into()])
+    }
+
+    fn request_account(&self, chain_id: &str) -> Result<Promise, String> {
+        call_promise(&self.wallet()?, "getKey", &[chain_id.into()])
+    }
+
+    fn parse_account(&self, raw: &JsValue) -> Result<WalletAccount, String> {
+        Ok(WalletAccount {
+            address: get_string(raw, "bech32Address").ok_or("Wallet returned no address")?,
+            name: get_string(raw, "name"),
+            pub_key: get_bytes(raw, "pubKey"),
+        })
+    }
+
+    fn request_sign_amino(&self, chain_id: &str, signer: &str, sign_doc: &JsValue) -> Result<Promise, String> {
+        call_promise(&self.wallet()?, "signAmino", &[chain_id.into(), signer.into(), sign_doc.clone()])
+    }
+
+    fn parse_sign_response(&self, raw: JsValue) -> Result<AminoSignResponse, String> {
+        raw.into_serde().map_err(|e| format!("Unexpected signAmino response: {:?}", e))
+    }
+
+    fn account_change_event(&self) -> &'static str {
+        self.account_event
+    }
+
+    // The extension's own signer, which signs direct (protobuf) like the baseline did
+    fn get_offline_signer(&self, chain_id: &str) -> Result<JsValue, String> {
+        call_method(&self.wallet()?, "getOfflineSigner", &[chain_id.into()])
+    }
+
+    fn signs_direct(&self) -> bool {
+        true
+    }
+}
+
+// Cosmostation's native API: window.cosmostation.cosmos.request({ method, params })
+pub struct Cosmostation;
+
+impl Cosmostation {
+    // Keplr-compatible interface of newer extensions, whose offline signer signs direct
+    fn keplr_interface(&self) -> Option<JsValue> {
+        window_global(&["cosmostation", "providers", "keplr"])
+    }
+
+    fn request(&self, method: &str, params: serde_json::Value) -> Result<Promise, String> {
+        let cosmos = window_global(&["cosmostation", "cosmos"]).ok_or("Cosmostation is not installed")?;
+        let message = serde_json::json!({ "method": method, "params": params });
+        let message = JsValue::from_serde(&message).map_err(|e| format!("Failed to build request: {:?}", e))?;
+        call_promise(&cosmos, "request", &[message])
+    }
+}
+
+impl WalletProvider for Cosmostation {
+    fn kind(&self) -> WalletKind {
+        WalletKind::Cosmostation
+    }
+
+    fn injected(&self) -> Option<JsValue> {
+        window_global(&["cosmostation", "cosmos"])
+    }
+
+    // Requesting the account is what prompts Cosmostation to connect
+    fn enable(&self, chain_id: &str) -> Result<Promise, String> {
+        self.request_account(chain_id)
+    }
+
+    fn request_account(&self, chain_id: &str) -> Result<Promise, String> {
+        self.request("cos_requestAccount", serde_json::json!({ "chainName": chain_id }))
+    }
+
+    fn parse_account(&self, raw: &JsValue) -> Result<WalletAccount, String> {
+        Ok(WalletAccount {
+            address: get_string(raw, "address").ok_or("Wallet returned no address")?,
+            name: get_string(raw, "name"),
+            pub_key: get_bytes(raw, "publicKey"),
+        })
+    }
+
+    fn request_sign_amino(&self, chain_id: &str, _signer: &str, sign_doc: &JsValue) -> Result<Promise, String> {
+        let doc: serde_json::Value = sign_doc.into_serde().map_err(|e| format!("Invalid sign doc: {:?}", e))?;
+        self.request(
+            "cos_signAmino",
+            serde_json::json!({ "chainName": chain_id, "doc": doc, "isEditFee": true, "isEditMemo": true }),
+        )
+    }
+
+    // Cosmostation answers with { signature, pub_key, signed_doc }
+    fn parse_sign_response(&self, raw: JsValue) -> Result<AminoSignResponse, String> {
+        let value: serde_json::Value = raw.into_serde().map_err(|e| format!("Unexpected cos_signAmino response: {:?}", e))?;
+        Ok(AminoSignResponse {
+            signed: value.get("signed_doc").cloned().unwrap_or_default(),
+            signature: serde_json::json!({
+                "pub_key": value.get("pub_key").cloned().unwrap_or_default(),
+                "signature": value.get("signature").cloned().unwrap_or_default(),
+            }),
+        })
+    }
+
+    fn account_change_event(&self) -> &'static str {
+        "cosmostation_keystorechange"
+    }
+
+    // Older extensions without the Keplr interface only get the amino signer built on cos_signAmino
+    fn get_offline_signer(&self, chain_id: &str) -> Result<JsValue, String> {
+        match self.keplr_interface() {
+            Some(keplr) => call_method(&keplr, "getOfflineSigner", &[chain_id.into()]),
+            None => Ok(build_offline_signer(self.kind(), chain_id)),
+        }
+    }
+
+    fn signs_direct(&self) -> bool {
+        self.keplr_interface().is_some()
+    }
+}
+
+// Publish the selected provider's signer as window.getActiveOfflineSigner(chainId) so
+// wallet.js helpers sign with whichever wallet the user picked
+pub fn expose_active_signer(kind: WalletKind) {
+    let get_signer = Closure::wrap(Box::new(move |chain_id: String| {
+        kind.provider().get_offline_signer(&chain_id).map_err(|e| JsValue::from_str(&e))
+    }) as Box<dyn Fn(String) -> Result<JsValue, JsValue>>);
+
+    if let Some(window) = web_sys::window() {
+        Reflect::set(&window, &"getActiveOfflineSigner".into(), get_signer.as_ref()).ok();
+    }
+    get_signer.forget();
+}
+
+// Remember the connected wallet so the next visit can reconnect silently
+pub fn remember_wallet(kind: WalletKind) {
+    storage::save(WALLET_STORAGE_KEY, &kind);
+}
+
+pub fn forget_wallet() {
+    storage::remove(WALLET_STORAGE_KEY);
+}
+
+pub fn remembered_wallet() -> Option<WalletKind> {
+    storage::load(WALLET_STORAGE_KEY)
+}
+
+// Call `on_change` with the provider whenever any installed wallet reports an account switch
+pub fn listen_for_account_changes(on_change: impl Fn(WalletKind) + 'static) {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let on_change = Rc::new(on_change);
+
+    for kind in WalletKind::ALL {
+        let on_change = on_change.clone();
+        let listener = Closure::wrap(Box::new(move || on_change(kind)) as Box<dyn Fn()>);
+        if let Err(err) = window.add_event_listener_with_callback(
+            kind.provider().account_change_event(),
+            listener.as_ref().unchecked_ref(),
+        ) {
+            log::error!("❌ Failed to listen for {} account changes: {:?}", kind.name(), err);
+        }
+        listener.forget();
+    }
+}
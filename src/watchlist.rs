@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+use crate::bech32;
+use crate::chains::{self, ChainConfig};
+use crate::storage;
+
+// localStorage key holding the recently watched addresses
+const WATCHLIST_STORAGE_KEY: &str = "yolonode.watchlist";
+
+// How many watched addresses we keep around
+const MAX_WATCHED: usize = 5;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WatchedAddress {
+    pub address: String,
+    pub label: String,
+}
+
+pub fn load_watchlist() -> Vec<WatchedAddress> {
+    storage::load(WATCHLIST_STORAGE_KEY).unwrap_or_default()
+}
+
+// Move `entry` to the front of the list, dropping the oldest beyond MAX_WATCHED
+pub fn remember(watchlist: &mut Vec<WatchedAddress>, entry: WatchedAddress) {
+    watchlist.retain(|w| w.address != entry.address);
+    watchlist.insert(0, entry);
+    watchlist.truncate(MAX_WATCHED);
+    storage::save(WATCHLIST_STORAGE_KEY, watchlist);
+}
+
+pub fn forget(watchlist: &mut Vec<WatchedAddress>, address: &str) {
+    watchlist.retain(|w| w.address != address);
+    storage::save(WATCHLIST_STORAGE_KEY, watchlist);
+}
+
+// Check the checksum and that the prefix belongs to a chain we can query
+pub fn validate_watch_address(address: &str) -> Result<ChainConfig, String> {
+    let decoded = bech32::decode(address.trim()).map_err(|e| e.to_string())?;
+    if decoded.bytes.len() != 20 && decoded.bytes.len() != 32 {
+        return Err(format!("Unexpected address length of {} bytes", decoded.bytes.len()));
+    }
+    chains::find_chain_by_prefix(&decoded.prefix)
+        .ok_or_else(|| format!("Unsupported address prefix \"{}\"", decoded.prefix))
+}
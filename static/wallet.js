@@ -77,133 +77,526 @@ async function getAddressForMultiChain(chainId) {
             return "";
         }
     } else {
-        showModal("Wallet not found! Please install Keplr or Fina wallet.");
+        showModal("Wallet not found! Please install Keplr, Fina, Leap or Cosmostation wallet.");
         return "";
     }
 }
 
-// Function to fetch governance proposals
-async function fetchGovernanceProposals(limit = 50) {
-    if (!window.keplr) {
-        showModal("Wallet not found! Please install Keplr or Fina wallet.");
-        return [];
-    }
+// Flatten a gov proposal into the shape the Vote view deserializes
+function processProposal(proposal) {
+    return {
+        ...proposal,
+        proposal_id: proposal.proposal_id || proposal.id || "Unknown",
+    };
+}
 
+// Function to fetch governance proposals of any chain from its LCD, the whole history newest first
+// (the Vote view pages through it)
+async function fetchGovernanceProposals(lcd = "https://rpc.ankr.com/http/scrt_cosmos") {
     try {
-        console.log("🔍 Fetching latest governance proposals...");
-
-        await window.keplr.enable("secret-4");
-        const signer = window.getOfflineSigner("secret-4");
-        const accounts = await signer.getAccounts();
-        const walletAddress = accounts[0]?.address;
-
-        if (!walletAddress) {
-            console.error("❌ No wallet address found.");
-            return [];
-        }
-
-        const client = new window.SecretNetworkClient({
-            url: "https://rpc.ankr.com/http/scrt_cosmos",
-            chainId: "secret-4",
-            wallet: signer,
-            walletAddress,
-        });        
+        console.log(`🔍 Fetching governance proposals from ${lcd}...`);
 
         let allProposals = [];
         let nextKey = null;
-        let latestProposalId = null;
-
-        // Step 1: Fetch the latest proposal ID
-        const latestProposalResponse = await client.query.gov.proposals({
-            "pagination.limit": 1,
-            "pagination.reverse": true,
-        });
-
-        if (latestProposalResponse?.proposals?.length > 0) {
-            latestProposalId = parseInt(latestProposalResponse.proposals[0].proposal_id);
-            console.log(`✅ Latest Proposal ID: ${latestProposalId}`);
-        } else {
-            console.error("❌ Unable to fetch the latest proposal.");
-            return [];
-        }
 
-        // Step 2: Fetch proposals in descending order using pagination
+        // Proposals are public, so no wallet is needed; fetch them in descending order using pagination
         do {
-            const response = await client.query.gov.proposals({
-                "pagination.key": nextKey || undefined, // Use pagination key
-                "pagination.limit": 50, // Fetch 50 at a time
-                "pagination.reverse": true, // Fetch newest first
+            const params = new URLSearchParams({
+                "pagination.limit": "50", // Fetch 50 at a time
+                "pagination.reverse": "true", // Fetch newest first
             });
+            if (nextKey) {
+                params.set("pagination.key", nextKey);
+            }
+            const response = await fetch(`${lcd}/cosmos/gov/v1/proposals?${params}`, { mode: 'cors' });
+            if (!response.ok) {
+                throw new Error(`${response.status} ${response.statusText}`);
+            }
+            const data = await response.json();
 
-            if (response?.proposals?.length > 0) {
-                const processedProposals = response.proposals.map((proposal) => {
-                    let contentDetails = {
-                        title: "No content available",
-                        description: "No description available",
-                    };
-                
-                    if (proposal.content) {
-                        contentDetails = {
-                            title: proposal.content.title || "Untitled Proposal",
-                            description: proposal.content.description || "No description available",
-                        };
-                    } else if (proposal.messages && proposal.messages.length > 0) {
-                        const firstMessage = proposal.messages[0];
-                        contentDetails = {
-                            title: `Message Type: ${firstMessage["@type"] || "Unknown"}`,
-                            description: `Details: ${JSON.stringify(firstMessage)}`,
-                        };
-                    }
-                
-                    let formattedEndDate = "Unknown";
-                    if (proposal.voting_end_time) {
-                        try {
-                            const votingEndTime = new Date(proposal.voting_end_time);
-                
-                            if (!isNaN(votingEndTime.getTime())) {
-                                formattedEndDate = votingEndTime.toISOString().replace("T", " ").split(".")[0] + " UTC";
-                            } else {
-                                console.warn(`⚠️ Invalid voting_end_time for proposal ${proposal.proposal_id}: ${proposal.voting_end_time}`);
-                            }
-                        } catch (error) {
-                            console.error(`❌ Error parsing voting_end_time for proposal ${proposal.proposal_id}:`, error);
-                        }
-                    }
-                
-                    return {
-                        proposal_id: proposal.proposal_id || proposal.id || "Unknown",
-                        title: contentDetails.title,
-                        description: contentDetails.description,
-                        status: proposal.status,
-                        expiration_time: formattedEndDate, 
-                        submit_time: proposal.submit_time,
-                        ...proposal,
-                    };
-                });                
-
-                allProposals = [...allProposals, ...processedProposals];
-                nextKey = response.pagination?.next_key;
+            if (data?.proposals?.length > 0) {
+                allProposals = [...allProposals, ...data.proposals.map(processProposal)];
+                nextKey = data.pagination?.next_key;
             } else {
                 console.warn("❌ No more proposals or empty response.");
                 break;
             }
         } while (nextKey);
 
-        // Step 3: Sort proposals by `submit_time` (newest first)
+        // Sort proposals by `submit_time` (newest first)
         allProposals.sort((a, b) => new Date(b.submit_time) - new Date(a.submit_time));
 
-        // Return only the most recent proposals up to the limit
-        console.log(`✅ Final list of ${limit} governance proposals:`, allProposals.slice(0, limit));
-        return allProposals.slice(0, limit);
+        console.log(`✅ Fetched ${allProposals.length} governance proposals`);
+        return allProposals;
     } catch (error) {
         console.error("❌ Error fetching governance proposals:", error);
         return [];
     }
 }
 
+// Fetch a single proposal, e.g. to refresh it after a deposit
+async function fetchGovernanceProposal(lcd, proposalId) {
+    const response = await fetch(`${lcd}/cosmos/gov/v1/proposals/${proposalId}`, { mode: 'cors' });
+    if (!response.ok) {
+        throw new Error(`${response.status} ${response.statusText}`);
+    }
+    const data = await response.json();
+    if (!data?.proposal) {
+        throw new Error(`Proposal ${proposalId} not found`);
+    }
+    return processProposal(data.proposal);
+}
+
+// Fetch bank balances, delegations and pending rewards for any address (no wallet needed)
+async function fetchAccountSummary(lcd, address) {
+    const get = async (path) => {
+        const response = await fetch(`${lcd}${path}`, { mode: 'cors' });
+        if (!response.ok) {
+            throw new Error(`${response.status} ${response.statusText} for ${path}`);
+        }
+        return response.json();
+    };
+
+    try {
+        console.log(`🔍 Fetching account summary for ${address}...`);
+        const [balances, delegations, rewards] = await Promise.all([
+            get(`/cosmos/bank/v1beta1/balances/${address}`),
+            get(`/cosmos/staking/v1beta1/delegations/${address}`),
+            // Rewards are optional; some LCDs reject the query for accounts without delegations
+            get(`/cosmos/distribution/v1beta1/delegators/${address}/rewards`).catch(() => ({ total: [] })),
+        ]);
+
+        return {
+            balances: balances.balances || [],
+            delegations: (delegations.delegation_responses || []).map((d) => ({
+                validator_address: d.delegation.validator_address,
+                balance: d.balance,
+            })),
+            rewards: rewards.total || [],
+        };
+    } catch (error) {
+        console.error("❌ Error fetching account summary:", error);
+        throw error;
+    }
+}
+
+// Fetch how `voter` voted on a proposal; resolves to null if they haven't voted
+async function fetchProposalVote(lcd, proposalId, voter) {
+    const response = await fetch(`${lcd}/cosmos/gov/v1/proposals/${proposalId}/votes/${voter}`, { mode: 'cors' });
+    if (response.ok) {
+        const data = await response.json();
+        return data.vote || null;
+    }
+
+    // The LCD answers "not found" (HTTP 400/404, gRPC code 5) when there is no vote
+    const error = await response.json().catch(() => ({}));
+    if (response.status === 404 || error.code === 5 || /not found/i.test(error.message || "")) {
+        return null;
+    }
+    throw new Error(`${response.status} ${error.message || response.statusText}`);
+}
+
+// Votes of several addresses on one proposal, in order: { vote } (null if they haven't voted) or
+// { error } when the lookup failed. At most `concurrency` requests run at once to spare the LCD.
+async function fetchVotesOf(lcd, proposalId, voters, concurrency = 8) {
+    const results = new Array(voters.length);
+    let next = 0;
+    const worker = async () => {
+        while (next < voters.length) {
+            const index = next++;
+            const voter = voters[index];
+            if (!voter) {
+                results[index] = { error: "invalid address" };
+                continue;
+            }
+            try {
+                results[index] = { vote: await fetchProposalVote(lcd, proposalId, voter) };
+            } catch (error) {
+                results[index] = { error: error.message || String(error) };
+            }
+        }
+    };
+    await Promise.all(Array.from({ length: Math.min(concurrency, voters.length) }, worker));
+    return results;
+}
+
+// Fetch the bonded validator set with monikers and voting power
+async function fetchValidators(lcd) {
+    let validators = [];
+    let nextKey = null;
+    do {
+        const params = new URLSearchParams({ status: "BOND_STATUS_BONDED", "pagination.limit": "200" });
+        if (nextKey) {
+            params.set("pagination.key", nextKey);
+        }
+        const response = await fetch(`${lcd}/cosmos/staking/v1beta1/validators?${params}`, { mode: 'cors' });
+        if (!response.ok) {
+            throw new Error(`${response.status} ${response.statusText}`);
+        }
+        const data = await response.json();
+        validators = [
+            ...validators,
+            ...(data.validators || []).map((v) => ({
+                operator_address: v.operator_address,
+                moniker: v.description?.moniker || v.operator_address,
+                tokens: v.tokens,
+            })),
+        ];
+        nextKey = data.pagination?.next_key;
+    } while (nextKey);
+    return validators;
+}
+
+// Gov tally params and bonded stake, everything needed to judge whether a proposal passes
+async function fetchTallyContext(lcd) {
+    const get = async (path) => {
+        const response = await fetch(`${lcd}${path}`, { mode: 'cors' });
+        if (!response.ok) {
+            throw new Error(`${response.status} ${response.statusText} for ${path}`);
+        }
+        return response.json();
+    };
+
+    const [params, pool] = await Promise.all([
+        get("/cosmos/gov/v1/params/tallying"),
+        get("/cosmos/staking/v1beta1/pool"),
+    ]);
+    // SDK 0.47+ moved everything into `params`; older chains only fill `tally_params`
+    const tally = params.params || params.tally_params || {};
+    return {
+        quorum: tally.quorum,
+        threshold: tally.threshold,
+        expedited_threshold: tally.expedited_threshold || "",
+        veto_threshold: tally.veto_threshold,
+        bonded_tokens: pool.pool?.bonded_tokens || "0",
+    };
+}
+
+// Current tally of a proposal in its voting period
+async function fetchProposalTally(lcd, proposalId) {
+    const response = await fetch(`${lcd}/cosmos/gov/v1/proposals/${proposalId}/tally`, { mode: 'cors' });
+    if (!response.ok) {
+        throw new Error(`${response.status} ${response.statusText}`);
+    }
+    const data = await response.json();
+    return data.tally;
+}
+
+// Latest height and time plus the average block time over the last 1000 blocks
+async function fetchBlockTiming(lcd, sample = 1000) {
+    const getBlock = async (height) => {
+        const response = await fetch(`${lcd}/cosmos/base/tendermint/v1beta1/blocks/${height}`, { mode: 'cors' });
+        if (!response.ok) {
+            throw new Error(`${response.status} ${response.statusText}`);
+        }
+        const header = (await response.json()).block.header;
+        return { height: parseInt(header.height), time: new Date(header.time).getTime() };
+    };
+
+    const latest = await getBlock("latest");
+    const earlier = await getBlock(Math.max(1, latest.height - sample));
+    const blocks = latest.height - earlier.height;
+    return {
+        height: latest.height,
+        time_ms: latest.time,
+        seconds_per_block: blocks > 0 ? (latest.time - earlier.time) / 1000 / blocks : 6,
+    };
+}
+
+// Minimum deposit a proposal needs to enter its voting period
+async function fetchDepositParams(lcd) {
+    const response = await fetch(`${lcd}/cosmos/gov/v1/params/deposit`, { mode: 'cors' });
+    if (!response.ok) {
+        throw new Error(`${response.status} ${response.statusText}`);
+    }
+    const data = await response.json();
+    const params = data.params || data.deposit_params || {};
+    return { min_deposit: params.min_deposit || [] };
+}
+
+// Depositors of a proposal and how much each put in
+async function fetchProposalDeposits(lcd, proposalId) {
+    const response = await fetch(`${lcd}/cosmos/gov/v1/proposals/${proposalId}/deposits?pagination.limit=200`, { mode: 'cors' });
+    if (!response.ok) {
+        throw new Error(`${response.status} ${response.statusText}`);
+    }
+    const data = await response.json();
+    return (data.deposits || []).map((d) => ({ depositor: d.depositor, amount: d.amount || [] }));
+}
+
+// Page through the txs matching all `conditions`, newest first, handing each successful tx to
+// `onTx`. Returns true when it stopped at `maxTxs` with more txs left, i.e. the scan is incomplete.
+async function scanTxs(lcd, conditions, maxTxs, onTx) {
+    const search = async (offset, useQuery) => {
+        const params = new URLSearchParams({
+            "pagination.limit": "100",
+            "pagination.offset": String(offset),
+            order_by: "ORDER_BY_DESC",
+        });
+        // SDK 0.50 replaced the repeated `events` parameter with a single `query`
+        if (useQuery) {
+            params.set("query", conditions.join(" AND "));
+        } else {
+            conditions.forEach((condition) => params.append("events", condition));
+        }
+        return fetch(`${lcd}/cosmos/tx/v1beta1/txs?${params}`, { mode: 'cors' });
+    };
+
+    let useQuery = false;
+    for (let offset = 0; offset < maxTxs; offset += 100) {
+        let response = await search(offset, useQuery);
+        if (!response.ok && offset === 0 && !useQuery) {
+            useQuery = true;
+            response = await search(offset, useQuery);
+        }
+        if (!response.ok) {
+            throw new Error(`${response.status} ${response.statusText}`);
+        }
+        const data = await response.json();
+        const txs = data.txs || [];
+        const responses = data.tx_responses || [];
+        txs.forEach((tx, i) => {
+            if (!responses[i]?.code) {
+                onTx(tx);
+            }
+        });
+        if (txs.length < 100) {
+            return false;
+        }
+    }
+    return true;
+}
+
+// Votes by `voter` among `messages`, including ones cast by a grantee through authz MsgExec
+function collectVotes(messages, voter, votes) {
+    for (const msg of messages || []) {
+        const type = msg["@type"] || "";
+        if (/\.MsgExec$/.test(type)) {
+            collectVotes(msg.msgs, voter, votes);
+            continue;
+        }
+        if (!/\.MsgVote(Weighted)?$/.test(type) || msg.voter !== voter || votes.has(msg.proposal_id)) {
+            continue;
+        }
+        const options = msg.options || [{ option: msg.option, weight: "1.000000000000000000" }];
+        votes.set(msg.proposal_id, { proposal_id: msg.proposal_id, voter, options });
+    }
+}
+
+// Every gov vote an address has cast, found through its transactions since votes are pruned
+// from gov state once a proposal is tallied. The latest vote per proposal wins. Votes through
+// authz are signed by the grantee, so they are found by the vote event's voter instead (SDK 0.47+).
+async function fetchVoteHistory(lcd, voter, maxTxs = 1000) {
+    const votes = new Map();
+    const onTx = (tx) => collectVotes(tx.body?.messages, voter, votes);
+    const truncated = await scanTxs(lcd, [`message.sender='${voter}'`, "message.module='governance'"], maxTxs, onTx);
+    let grantTruncated = false;
+    try {
+        grantTruncated = await scanTxs(lcd, [`proposal_vote.voter='${voter}'`], maxTxs, onTx);
+    } catch (error) {
+        console.warn("⚠️ Searching votes by voter isn't supported here:", error);
+    }
+    return { votes: [...votes.values()], truncated: truncated || grantTruncated, max_txs: maxTxs };
+}
+
+// Current params of a module, from its params query (path relative to the LCD)
+async function fetchModuleParams(lcd, path) {
+    const response = await fetch(`${lcd}${path}`, { mode: 'cors' });
+    if (!response.ok) {
+        throw new Error(`${response.status} ${response.statusText}`);
+    }
+    const data = await response.json();
+    return data.params ?? data;
+}
+
+// Address of a module account, e.g. fetchModuleAccount(lcd, "gov") for the gov authority
+async function fetchModuleAccount(lcd, name) {
+    const response = await fetch(`${lcd}/cosmos/auth/v1beta1/module_accounts/${name}`, { mode: 'cors' });
+    if (!response.ok) {
+        throw new Error(`${response.status} ${response.statusText}`);
+    }
+    const data = await response.json();
+    const account = data.account || {};
+    const address = account.base_account?.address || account.address;
+    if (!address) {
+        throw new Error(`no address for module account ${name}`);
+    }
+    return { address };
+}
+
+// Off-chain proposal metadata (an IPFS gateway or plain URL), which must be a JSON document
+async function fetchProposalMetadata(url) {
+    const response = await fetch(url, { mode: 'cors' });
+    if (!response.ok) {
+        throw new Error(`${response.status} ${response.statusText}`);
+    }
+    const text = await response.text();
+    try {
+        return JSON.parse(text);
+    } catch (error) {
+        throw new Error("metadata is not JSON");
+    }
+}
+
+// Gov vote options as secretjs' VoteOption enum values
+const VOTE_OPTIONS = { Yes: 1, Abstain: 2, No: 3, NoWithVeto: 4 };
+
+// Signing client for the wallet the user picked in the app (see getActiveOfflineSigner).
+// Gov messages are plain Cosmos SDK messages, so with a direct signer this works against any
+// chain's LCD; fees are paid in the chain's fee denom.
+async function createSigningClient(chainId = "secret-4", url = "https://rpc.ankr.com/http/scrt_cosmos") {
+    if (!window.getActiveOfflineSigner) {
+        throw new Error("No wallet connected");
+    }
+    const signer = window.getActiveOfflineSigner(chainId);
+    const accounts = await signer.getAccounts();
+    const walletAddress = accounts[0]?.address;
+    if (!walletAddress) {
+        throw new Error("No wallet address found");
+    }
+    return new window.SecretNetworkClient({ url, chainId, wallet: signer, walletAddress });
+}
+
+// Gas limit for one gov message: a simulation plus 30% headroom, since gas costs differ between
+// chains, or `fallback` when the LCD can't simulate it
+async function gasLimitFor(tx, msg, fallback) {
+    try {
+        const simulation = await tx.simulate(msg);
+        const used = parseInt(simulation?.gas_info?.gas_used);
+        return used > 0 ? Math.ceil(used * 1.3) : fallback;
+    } catch (error) {
+        console.warn("⚠️ Gas simulation failed, using the default gas limit:", error);
+        return fallback;
+    }
+}
+
+// Normalize a secretjs broadcast response for Rust
+function txResult(tx) {
+    return {
+        tx_hash: tx.transactionHash,
+        code: tx.code,
+        raw_log: tx.rawLog || "",
+    };
+}
+
+// Vote on a proposal with the connected wallet, e.g. voteOnProposal(313, "Yes")
+async function voteOnProposal(proposalId, option, chainId = "secret-4", lcd = undefined, feeDenom = "uscrt") {
+    const voteOption = typeof option === "number" ? option : VOTE_OPTIONS[option];
+    if (!voteOption) {
+        throw new Error(`Unknown vote option: ${option}`);
+    }
+
+    console.log(`🗳️ Voting ${option} on ${chainId} proposal ${proposalId}...`);
+    const client = await createSigningClient(chainId, lcd);
+    const msg = {
+        voter: client.address,
+        proposal_id: String(proposalId),
+        option: voteOption,
+    };
+    const gasLimit = await gasLimitFor(client.tx.gov.vote, msg, 50_000);
+    const tx = await client.tx.gov.vote(msg, { gasLimit, feeDenom });
+    console.log("✅ Vote broadcast:", tx.transactionHash, tx.code === 0 ? "success" : tx.rawLog);
+    return txResult(tx);
+}
+
+// Deposit on a proposal in its deposit period, amount in base units, e.g. depositOnProposal(320, "1000000", "uscrt")
+async function depositOnProposal(proposalId, amount, denom, chainId = "secret-4", lcd = undefined, feeDenom = denom) {
+    console.log(`💰 Depositing ${amount}${denom} on ${chainId} proposal ${proposalId}...`);
+    const client = await createSigningClient(chainId, lcd);
+    const msg = {
+        depositor: client.address,
+        proposal_id: String(proposalId),
+        amount: [{ denom, amount: String(amount) }],
+    };
+    const gasLimit = await gasLimitFor(client.tx.gov.deposit, msg, 50_000);
+    const tx = await client.tx.gov.deposit(msg, { gasLimit, feeDenom });
+    console.log("✅ Deposit broadcast:", tx.transactionHash, tx.code === 0 ? "success" : tx.rawLog);
+    return txResult(tx);
+}
+
+// Split vote, e.g. voteWeightedOnProposal(313, [{ option: "Yes", weight: "0.7" }, { option: "Abstain", weight: "0.3" }])
+async function voteWeightedOnProposal(proposalId, options, chainId = "secret-4", lcd = undefined, feeDenom = "uscrt") {
+    const weightedOptions = options.map(({ option, weight }) => {
+        const voteOption = typeof option === "number" ? option : VOTE_OPTIONS[option];
+        if (!voteOption) {
+            throw new Error(`Unknown vote option: ${option}`);
+        }
+        return { option: voteOption, weight: String(weight) };
+    });
+
+    console.log(`🗳️ Weighted vote on ${chainId} proposal ${proposalId}:`, options);
+    const client = await createSigningClient(chainId, lcd);
+    const msg = {
+        voter: client.address,
+        proposal_id: String(proposalId),
+        options: weightedOptions,
+        metadata: "",
+    };
+    const gasLimit = await gasLimitFor(client.tx.gov.voteWeighted, msg, 60_000);
+    const tx = await client.tx.gov.voteWeighted(msg, { gasLimit, feeDenom });
+    console.log("✅ Weighted vote broadcast:", tx.transactionHash, tx.code === 0 ? "success" : tx.rawLog);
+    return txResult(tx);
+}
+
+// Proposal messages (JSON with "@type") that secretjs can encode, mapped to its Msg classes
+const PROPOSAL_MESSAGES = {
+    "/cosmos.distribution.v1beta1.MsgCommunityPoolSpend": ({ authority, recipient, amount }) => {
+        if (!window.MsgCommunityPoolSpend) {
+            throw new Error("secret.bundle.js is out of date (no MsgCommunityPoolSpend); rebuild it with webpack");
+        }
+        return new window.MsgCommunityPoolSpend({ authority, recipient, amount });
+    },
+};
+
+// Submit a gov v1 proposal built in the app, e.g.
+// submitProposal({ messages: [], initial_deposit: [{ denom: "uscrt", amount: "1000000" }], metadata: "", title, summary })
+async function submitProposal(proposal, chainId = "secret-4", lcd = undefined, feeDenom = "uscrt") {
+    const messages = proposal.messages.map((message) => {
+        const toMsg = PROPOSAL_MESSAGES[message["@type"]];
+        if (!toMsg) {
+            throw new Error(`${message["@type"]} can't be signed in the browser; export the proposal and use the CLI`);
+        }
+        return toMsg(message);
+    });
+
+    console.log(`📝 Submitting proposal "${proposal.title}" on ${chainId}...`);
+    const client = await createSigningClient(chainId, lcd);
+    const msg = {
+        proposer: client.address,
+        messages,
+        initial_deposit: proposal.initial_deposit,
+        metadata: proposal.metadata,
+        title: proposal.title,
+        summary: proposal.summary,
+        expedited: false,
+    };
+    const gasLimit = await gasLimitFor(client.tx.gov.submitProposal, msg, 300_000);
+    const tx = await client.tx.gov.submitProposal(msg, { gasLimit, feeDenom });
+    console.log("✅ Proposal broadcast:", tx.transactionHash, tx.code === 0 ? "success" : tx.rawLog);
+    return txResult(tx);
+}
+
 // Attach functions to the global window object for Rust to access
 window.connectKeplrWallet = connectKeplrWallet;
 window.disconnectKeplrWallet = disconnectKeplrWallet;
 window.get_wallet_address = get_wallet_address;
 window.fetchGovernanceProposals = fetchGovernanceProposals;
 window.getAddressForMultiChain = getAddressForMultiChain;
+window.fetchGovernanceProposal = fetchGovernanceProposal;
+window.fetchAccountSummary = fetchAccountSummary;
+window.fetchProposalVote = fetchProposalVote;
+window.fetchVoteHistory = fetchVoteHistory;
+window.fetchVotesOf = fetchVotesOf;
+window.fetchValidators = fetchValidators;
+window.fetchTallyContext = fetchTallyContext;
+window.fetchProposalTally = fetchProposalTally;
+window.fetchBlockTiming = fetchBlockTiming;
+window.fetchDepositParams = fetchDepositParams;
+window.fetchProposalDeposits = fetchProposalDeposits;
+window.fetchModuleParams = fetchModuleParams;
+window.fetchProposalMetadata = fetchProposalMetadata;
+window.fetchModuleAccount = fetchModuleAccount;
+window.voteOnProposal = voteOnProposal;
+window.depositOnProposal = depositOnProposal;
+window.voteWeightedOnProposal = voteWeightedOnProposal;
+window.submitProposal = submitProposal;